@@ -0,0 +1,56 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, fs, path::Path};
+
+/// Registers every solution module `years/<year>/<day>.rs` by generating `$OUT_DIR/days.rs`,
+/// which is included by the `days` module of the library. The solutions are compiled once there
+/// and shared by the runner and every command that needs them.
+fn main() {
+    println!("cargo:rerun-if-changed=years");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let years_dir = Path::new(&manifest_dir).join("years");
+
+    let mut days: Vec<(u16, u8, String)> = vec![];
+    for year_dir in fs::read_dir(&years_dir).into_iter().flatten().flatten() {
+        let Some(year) = year_dir.file_name().to_str().and_then(|y| y.parse().ok()) else {
+            continue;
+        };
+        days.extend(fs::read_dir(year_dir.path()).unwrap().filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+            let is_day = path.extension()? == "rs"
+                && stem.len() == 2
+                && stem.chars().all(|c| c.is_ascii_digit());
+            if !is_day {
+                return None;
+            }
+            Some((year, stem.parse().ok()?, path.to_str()?.to_string()))
+        }));
+    }
+    days.sort();
+
+    let mut modules = String::new();
+    let mut registrations = String::new();
+
+    for (year, day, path) in &days {
+        modules += &format!(
+            "#[allow(dead_code)]\n#[path = {:?}]\npub mod year{}_day{:02};\n\n",
+            path, year, day
+        );
+        registrations += &format!(
+            "    registry.register::<year{0}_day{1:02}::Day{1:02}>({0});\n",
+            year, day
+        );
+    }
+
+    let generated = format!(
+        "{}/// Every solution in `years`.\npub fn registry() -> crate::Registry {{\n    let mut registry = crate::Registry::new();\n{}    registry\n}}\n",
+        modules, registrations
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out_path, generated).unwrap();
+}
@@ -0,0 +1,321 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::client::Verdict;
+use crate::config::config;
+use crate::Outcome;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::{fs, io};
+
+pub enum AnswersError {
+    IoError(io::Error),
+    ParseError(toml::de::Error),
+    SerializeError(toml::ser::Error),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::IoError(e) => write!(f, "could not access answers file: {}", e),
+            AnswersError::ParseError(e) => write!(f, "could not parse answers file: {}", e),
+            AnswersError::SerializeError(e) => write!(f, "could not serialize answers: {}", e),
+        }
+    }
+}
+
+/// Accepted answers for a day's real input, stored in `answers/<day>.toml` of its year.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+    #[serde(default, skip_serializing_if = "Attempts::is_empty")]
+    pub part_one_attempts: Attempts,
+    #[serde(default, skip_serializing_if = "Attempts::is_empty")]
+    pub part_two_attempts: Attempts,
+}
+
+/// What is known about wrong answers submitted for a part, see `cargo submit`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempts {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+    /// Highest answer known to be too low.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<i64>,
+    /// Lowest answer known to be too high.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<i64>,
+}
+
+impl Attempts {
+    pub fn is_empty(&self) -> bool {
+        self == &Attempts::default()
+    }
+}
+
+impl Answers {
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        config()
+            .paths
+            .dir("answers", year)
+            .join(format!("{:02}.toml", day))
+    }
+
+    /// Returns `None` if no answers were recorded for `day` yet.
+    pub fn load(year: u16, day: u8) -> Result<Option<Self>, AnswersError> {
+        match fs::read_to_string(Answers::path(year, day)) {
+            Ok(contents) => toml::from_str(&contents)
+                .map(Some)
+                .map_err(AnswersError::ParseError),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(AnswersError::IoError(e)),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> Result<PathBuf, AnswersError> {
+        let path = Answers::path(year, day);
+        let contents = toml::to_string(self).map_err(AnswersError::SerializeError)?;
+
+        fs::create_dir_all(path.parent().unwrap()).map_err(AnswersError::IoError)?;
+        fs::write(&path, contents).map_err(AnswersError::IoError)?;
+        Ok(path)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_one = Some(answer),
+            2 => self.part_two = Some(answer),
+            _ => panic!("invalid part: {}", part),
+        }
+    }
+
+    pub fn attempts(&self, part: u8) -> &Attempts {
+        match part {
+            1 => &self.part_one_attempts,
+            2 => &self.part_two_attempts,
+            _ => panic!("invalid part: {}", part),
+        }
+    }
+
+    fn attempts_mut(&mut self, part: u8) -> &mut Attempts {
+        match part {
+            1 => &mut self.part_one_attempts,
+            2 => &mut self.part_two_attempts,
+            _ => panic!("invalid part: {}", part),
+        }
+    }
+
+    /// Explains why submitting `answer` for `part` is pointless, `None` if it might be right.
+    pub fn reject(&self, part: u8, answer: &str) -> Option<String> {
+        if let Some(accepted) = self.get(part) {
+            return Some(if accepted == answer {
+                format!("\"{}\" was already accepted.", answer)
+            } else {
+                format!("part {} was already solved with \"{}\".", part, accepted)
+            });
+        }
+
+        let attempts = self.attempts(part);
+        if attempts.wrong.iter().any(|wrong| wrong == answer) {
+            return Some(format!(
+                "\"{}\" was already submitted and is wrong.",
+                answer
+            ));
+        }
+
+        let number: i64 = answer.parse().ok()?;
+        match (attempts.too_low, attempts.too_high) {
+            (Some(too_low), _) if number <= too_low => Some(format!(
+                "{} is too low, {} was already too low.",
+                number, too_low
+            )),
+            (_, Some(too_high)) if number >= too_high => Some(format!(
+                "{} is too high, {} was already too high.",
+                number, too_high
+            )),
+            _ => None,
+        }
+    }
+
+    /// Remembers the `verdict` on `answer`, so it is not submitted again.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) {
+        if verdict == Verdict::Correct {
+            self.set(part, answer.to_string());
+            return;
+        }
+
+        if !matches!(
+            verdict,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        ) {
+            return;
+        }
+
+        let attempts = self.attempts_mut(part);
+        if !attempts.wrong.iter().any(|wrong| wrong == answer) {
+            attempts.wrong.push(answer.to_string());
+        }
+
+        if let Ok(number) = answer.parse::<i64>() {
+            match verdict {
+                Verdict::TooLow => {
+                    attempts.too_low = Some(attempts.too_low.map_or(number, |n| n.max(number)))
+                }
+                Verdict::TooHigh => {
+                    attempts.too_high = Some(attempts.too_high.map_or(number, |n| n.min(number)))
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Passed,
+    /// The answer differs from the recorded one.
+    Failed {
+        expected: String,
+        actual: String,
+    },
+    /// The part panicked or returned an error.
+    Errored(String),
+    /// The part returned no answer or was not run.
+    Unsolved,
+    /// The part was solved, but there is no recorded answer to check against.
+    NotRecorded,
+}
+
+impl Check {
+    pub fn new(expected: Option<&str>, outcome: &Outcome) -> Self {
+        if let Some(e) = outcome.error() {
+            return Check::Errored(e);
+        }
+
+        match (expected, outcome) {
+            (Some(expected), Outcome::Solved(actual)) if expected == actual => Check::Passed,
+            (Some(expected), Outcome::Solved(actual)) => Check::Failed {
+                expected: expected.to_string(),
+                actual: actual.clone(),
+            },
+            (None, Outcome::Solved(_)) => Check::NotRecorded,
+            _ => Check::Unsolved,
+        }
+    }
+}
+
+/// Line-by-line diff of two answers, prefixing lines only in `expected` with `-` and lines only
+/// in `actual` with `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    let mut out = vec![];
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => out.push(format!("  {}", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    out.push(format!("- {}", e));
+                }
+                if let Some(a) = a {
+                    out.push(format!("+ {}", a));
+                }
+            }
+        }
+    }
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let mut answers = Answers::default();
+        answers.set(1, "24000".into());
+        answers.set(2, "##..\n..##".into());
+
+        let serialized = toml::to_string(&answers).unwrap();
+        let parsed: Answers = toml::from_str(&serialized).unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(parsed.get(2), Some("##..\n..##"));
+
+        let partial: Answers = toml::from_str("part_one = \"7\"").unwrap();
+        assert_eq!(partial.get(1), Some("7"));
+        assert_eq!(partial.get(2), None);
+    }
+
+    #[test]
+    fn test_record_attempts() {
+        let mut answers = Answers::default();
+        answers.record(1, "50", Verdict::TooLow);
+        answers.record(1, "20", Verdict::TooLow);
+        answers.record(1, "900", Verdict::TooHigh);
+        answers.record(1, "abc", Verdict::Incorrect);
+        answers.record(1, "77", Verdict::TooRecent);
+
+        let attempts = answers.attempts(1);
+        assert_eq!(attempts.wrong, vec!["50", "20", "900", "abc"]);
+        assert_eq!((attempts.too_low, attempts.too_high), (Some(50), Some(900)));
+
+        assert!(answers.reject(1, "abc").is_some());
+        assert!(answers.reject(1, "49").is_some());
+        assert!(answers.reject(1, "1000").is_some());
+        assert_eq!(answers.reject(1, "77"), None);
+        assert_eq!(answers.reject(2, "50"), None);
+
+        answers.record(1, "77", Verdict::Correct);
+        assert_eq!(answers.get(1), Some("77"));
+        assert_eq!(
+            answers.reject(1, "78"),
+            Some("part 1 was already solved with \"77\".".into())
+        );
+
+        let serialized = toml::to_string(&answers).unwrap();
+        assert_eq!(toml::from_str::<Answers>(&serialized).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_check() {
+        let solved = Outcome::Solved("7".into());
+        assert_eq!(Check::new(Some("7"), &solved), Check::Passed);
+        assert_eq!(
+            Check::new(Some("8"), &solved),
+            Check::Failed {
+                expected: "8".into(),
+                actual: "7".into()
+            }
+        );
+        assert_eq!(Check::new(None, &solved), Check::NotRecorded);
+        assert_eq!(Check::new(Some("8"), &Outcome::Unsolved), Check::Unsolved);
+        assert_eq!(
+            Check::new(Some("8"), &Outcome::Panicked),
+            Check::Errored("panicked".into())
+        );
+        assert_eq!(
+            Check::new(None, &Outcome::Failed("bad input".into())),
+            Check::Errored("bad input".into())
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("1805", "1806"), "- 1805\n+ 1806");
+        assert_eq!(diff("#.\n.#", "#.\n##\n.."), "  #.\n- .#\n+ ##\n+ ..");
+    }
+}
@@ -0,0 +1,216 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config::config;
+use crate::guard::parse_seconds;
+use crate::{Outcome, PartResult};
+use std::time::{Duration, Instant};
+
+/// How long to spend on each part when benchmarking.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Time spent running the part before measuring.
+    pub warmup: Duration,
+    /// Time spent measuring, ignored if `iterations` is set.
+    pub budget: Duration,
+    /// Exact number of measured runs.
+    pub iterations: Option<u32>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: Duration::from_millis(100),
+            budget: Duration::from_secs(1),
+            iterations: None,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads `--bench`, `--iterations <n>` and `--bench-time <seconds>`, which take precedence
+    /// over `[bench]` in `aoc.toml`. Returns `None` unless benchmarking was requested.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let enabled = args.contains("--bench");
+        let iterations = args.opt_value_from_str("--iterations")?;
+        let budget = args.opt_value_from_fn("--bench-time", parse_seconds)?;
+
+        if !enabled {
+            return Ok(None);
+        }
+
+        let mut config = config().bench.to_bench_config();
+        if let Some(budget) = budget {
+            // a time on the command line wins over configured iterations.
+            config.budget = budget;
+            config.iterations = None;
+        }
+        if iterations.is_some() {
+            config.iterations = iterations;
+        }
+
+        Ok(Some(config))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Calls `run` repeatedly according to `config` and collects its timings.
+///
+/// Returns the result of the last run. Parts that are not solved are only run once and have no
+/// stats, since there is nothing meaningful to measure.
+pub fn bench(
+    config: &BenchConfig,
+    mut run: impl FnMut() -> PartResult,
+) -> (PartResult, Option<Stats>) {
+    let mut result = run();
+    if !matches!(result.outcome, Outcome::Solved(_)) {
+        return (result, None);
+    }
+
+    let warmup = Instant::now();
+    while warmup.elapsed() < config.warmup {
+        result = run();
+    }
+
+    let mut samples = vec![];
+    let measuring = Instant::now();
+    loop {
+        let done = match config.iterations {
+            Some(iterations) => samples.len() >= iterations.max(1) as usize,
+            None => !samples.is_empty() && measuring.elapsed() >= config.budget,
+        };
+        if done {
+            break;
+        }
+
+        result = run();
+        samples.push(result.elapsed);
+    }
+
+    let stats = Stats::from_samples(&samples);
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsString;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(6), ms(8)]);
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mean, ms(5));
+        // population stddev of 2, 4, 6, 8
+        assert_eq!(stats.stddev.as_micros(), 2236);
+
+        let stats = Stats::from_samples(&[ms(3), ms(1), ms(2)]);
+        assert_eq!(stats.median, ms(2));
+        assert_eq!(stats.stddev.as_micros(), 816);
+    }
+
+    #[test]
+    fn test_bench_args() {
+        let from_args = |args: &[&str]| {
+            let args = args.iter().map(OsString::from).collect();
+            BenchConfig::from_args(&mut pico_args::Arguments::from_vec(args))
+        };
+
+        assert!(from_args(&["--bench-time", "2"]).unwrap().is_none());
+        let config = from_args(&["--bench", "--bench-time", "0.5"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(config.budget, ms(500));
+        assert!(from_args(&["--bench", "--bench-time", "-1"]).is_err());
+        assert!(from_args(&["--bench", "--bench-time", "NaN"]).is_err());
+    }
+
+    #[test]
+    fn test_bench_iterations() {
+        let config = BenchConfig {
+            warmup: Duration::ZERO,
+            budget: Duration::ZERO,
+            iterations: Some(5),
+        };
+
+        let mut calls = 0;
+        let (result, stats) = bench(&config, || {
+            calls += 1;
+            PartResult {
+                day: 1,
+                part: 1,
+                outcome: Outcome::Solved("1".into()),
+                elapsed: ms(calls),
+                allocations: None,
+            }
+        });
+
+        // one initial run, five measured runs.
+        assert_eq!(calls, 6);
+        assert_eq!(result.elapsed, ms(6));
+        assert_eq!(stats.unwrap().min, ms(2));
+    }
+
+    #[test]
+    fn test_bench_unsolved() {
+        let mut calls = 0;
+        let (_, stats) = bench(&BenchConfig::default(), || {
+            calls += 1;
+            PartResult {
+                day: 1,
+                part: 2,
+                outcome: Outcome::Unsolved,
+                elapsed: ms(1),
+                allocations: None,
+            }
+        });
+
+        assert_eq!(calls, 1);
+        assert!(stats.is_none());
+    }
+}
@@ -2,45 +2,83 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::client::Client;
+use advent_of_code::{default_year, file_path, parse_day, puzzle};
+use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_fn(parse_day)?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(default_year),
     })
 }
 
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
 fn main() {
     let args = match parse_args() {
         Ok(args) => args,
-        Err(e) => {
-            eprintln!("Failed to process arguments: {}", e);
-            process::exit(1);
-        }
+        Err(e) => fail(&format!("Failed to process arguments: {}", e)),
+    };
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => fail(&format!("Failed to download: {}", e)),
+    };
+
+    println!("Fetching puzzle for day {}, {}...", args.day, args.year);
+    let markdown = match client.puzzle(args.year, args.day) {
+        Ok(markdown) => markdown,
+        Err(e) => fail(&format!("Failed to download the puzzle: {}", e)),
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    println!("Downloading input for day {}, {}...", args.day, args.year);
+    let input = match client.input(args.year, args.day) {
+        Ok(input) => input,
+        Err(e) => fail(&format!("Failed to download the input: {}", e)),
+    };
+
+    let puzzle_path = match puzzle::save(args.year, args.day, &markdown) {
+        Ok(path) => path,
+        Err(e) => fail(&format!("Failed to save the puzzle: {}", e)),
+    };
+
+    let input_path = file_path(args.year, "inputs", args.day);
+    let written = input_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&input_path, input));
+    if let Err(e) = written {
+        fail(&format!("Failed to save the input: {}", e));
     }
 
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            process::exit(1);
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    println!(
+        "🎄 Found {} code block(s), pick the examples with `cargo examples {}{}`.",
+        puzzle::code_blocks(&markdown).len(),
+        args.day,
+        match args.year == default_year() {
+            true => String::new(),
+            false => format!(" --year {}", args.year),
         }
-    }
+    );
 }
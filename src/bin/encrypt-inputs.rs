@@ -0,0 +1,128 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::vault::{encrypted_path, Key, KEY_VAR};
+use advent_of_code::{file_path, project_root};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process;
+
+struct Args {
+    generate_key: bool,
+    /// Only encrypt the inputs of this year instead of all years.
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        generate_key: args.contains("--generate-key"),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+    })
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// Creates the key file, readable only by its owner on unix.
+fn write_key(path: &Path, key: &Key) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(key.to_hex().as_bytes())
+}
+
+fn generate_key() {
+    let path = Key::path();
+    if path.exists() {
+        fail(&format!(
+            "\"{}\" already exists. Inputs encrypted with it could not be read with a new key.",
+            path.display()
+        ));
+    }
+
+    if let Err(e) = write_key(&path, &Key::generate()) {
+        fail(&format!("Failed to write \"{}\": {}", path.display(), e));
+    }
+
+    println!("🎄 Wrote a new key to \"{}\".", path.display());
+    println!(
+        "🎄 Keep it out of git and store it as the {} secret wherever inputs should be decrypted, e.g. in CI.",
+        KEY_VAR
+    );
+}
+
+/// Years with a directory in `years`, in order.
+fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = fs::read_dir(project_root().join("years"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .collect();
+    years.sort();
+    years
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => fail(&format!("Failed to process arguments: {}", e)),
+    };
+
+    if args.generate_key {
+        generate_key();
+        return;
+    }
+
+    let key = match Key::load() {
+        Ok(Some(key)) => key,
+        Ok(None) => fail("No key found. Run `cargo encrypt-inputs --generate-key` to create one."),
+        Err(e) => fail(&format!("Failed to load the key: {}", e)),
+    };
+
+    let years = match args.year {
+        Some(year) => vec![year],
+        None => years(),
+    };
+
+    let mut encrypted = 0;
+    for (year, day) in years
+        .into_iter()
+        .flat_map(|y| (1..=25).map(move |d| (y, d)))
+    {
+        let Ok(input) = fs::read_to_string(file_path(year, "inputs", day)) else {
+            continue;
+        };
+        // empty inputs are placeholders of `cargo scaffold`, not inputs.
+        if input.is_empty() {
+            continue;
+        }
+        let path = encrypted_path(year, day);
+
+        // a new nonce changes the whole file, so unchanged inputs are left as they are.
+        let unchanged = fs::read(&path)
+            .ok()
+            .and_then(|data| key.decrypt(&data).ok())
+            .is_some_and(|decrypted| decrypted == input);
+        if unchanged {
+            continue;
+        }
+
+        if let Err(e) = fs::write(&path, key.encrypt(&input)) {
+            fail(&format!("Failed to write \"{}\": {}", path.display(), e));
+        }
+        println!("Encrypted input to \"{}\"", path.display());
+        encrypted += 1;
+    }
+
+    println!("---");
+    println!("🎄 Encrypted {} changed input(s).", encrypted);
+}
@@ -0,0 +1,134 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{default_year, example_path, parse_day, project_root, puzzle};
+use std::{fs, process};
+
+/// Lines of each code block shown when listing them.
+const PREVIEW_LINES: usize = 4;
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// 1-based numbers of the code blocks to write, in order.
+    pick: Option<Vec<usize>>,
+    /// Overwrite examples that already have contents.
+    force: bool,
+}
+
+fn parse_pick(s: &str) -> Result<Vec<usize>, String> {
+    s.split(',')
+        .map(|n| match n.trim().parse() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(format!("invalid code block \"{}\"", n)),
+        })
+        .collect()
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_fn(parse_day)?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(default_year),
+        pick: args.opt_value_from_fn("--pick", parse_pick)?,
+        force: args.contains("--force"),
+    })
+}
+
+fn list(day: u8, blocks: &[String]) {
+    println!("🎄 Day {:02} has {} code block(s):", day, blocks.len());
+    for (i, block) in blocks.iter().enumerate() {
+        let lines: Vec<&str> = block.lines().collect();
+        println!("[{}] {} line(s)", i + 1, lines.len());
+        for line in lines.iter().take(PREVIEW_LINES) {
+            println!("    {}", line);
+        }
+        if lines.len() > PREVIEW_LINES {
+            println!("    ...");
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let markdown = match puzzle::load(args.year, args.day) {
+        Ok(Some(markdown)) => markdown,
+        Ok(None) => {
+            eprintln!(
+                "No puzzle found at \"{}\". Run `cargo download {}` first.",
+                puzzle::path(args.year, args.day).display(),
+                args.day
+            );
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to read the puzzle: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let blocks = puzzle::code_blocks(&markdown);
+
+    match &args.pick {
+        None => {
+            list(args.day, &blocks);
+            println!("---");
+            println!(
+                "🎄 Write examples with `cargo examples {} --pick 1,2`: the first to \"{:02}.txt\", the others to \"{:02}-2.txt\", ...",
+                args.day, args.day, args.day
+            );
+        }
+        Some(pick) => {
+            if let Some(n) = pick.iter().find(|&&n| n > blocks.len()) {
+                eprintln!(
+                    "There is no code block {}, see `cargo examples {}`.",
+                    n, args.day
+                );
+                process::exit(1);
+            }
+
+            for (i, &n) in pick.iter().enumerate() {
+                // `NN.txt` for the first picked block, `NN-2.txt`, `NN-3.txt`, ... for the others.
+                let name = match i {
+                    0 => String::new(),
+                    i => (i + 1).to_string(),
+                };
+                let path = example_path(args.year, args.day, &name);
+                // files of the project are shown relative to it, as in `years/2022/examples/01.txt`.
+                let shown = path.strip_prefix(project_root()).unwrap_or(&path).display();
+
+                // the empty example created by `cargo scaffold` is only a placeholder.
+                let edited = fs::metadata(&path).is_ok_and(|m| m.len() > 0);
+                if edited && !args.force {
+                    println!(
+                        "Kept example file \"{}\", it is already present. Pass --force to overwrite it.",
+                        shown
+                    );
+                    continue;
+                }
+
+                if let Err(e) = fs::write(&path, &blocks[n - 1]) {
+                    eprintln!("Failed to write \"{}\": {}", path.display(), e);
+                    process::exit(1);
+                }
+                println!("🎄 Wrote code block {} to \"{}\".", n, shown);
+            }
+        }
+    }
+
+    for part in 1..=2 {
+        if let Some(answer) = puzzle::expected_answer(&markdown, part) {
+            println!("🎄 Expected answer for part {}: {}", part, answer);
+        }
+    }
+}
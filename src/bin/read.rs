@@ -2,19 +2,25 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::client::Client;
+use advent_of_code::{default_year, parse_day, puzzle};
 use std::process;
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
+    /// Download the puzzle again before reading it, e.g. once part two is unlocked.
+    refresh: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_fn(parse_day)?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(default_year),
+        refresh: args.contains("--refresh"),
     })
 }
 
@@ -27,20 +33,46 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
+    if !args.refresh {
+        match puzzle::load(args.year, args.day) {
+            Ok(Some(markdown)) => {
+                println!("{}", puzzle::render(&markdown));
+                return;
+            }
+            Ok(None) => {
+                eprintln!(
+                    "No puzzle found at \"{}\". Run `cargo download {}` or pass `--refresh`.",
+                    puzzle::path(args.year, args.day).display(),
+                    args.day
+                );
                 process::exit(1);
             }
+            Err(e) => {
+                eprintln!("Failed to read the puzzle: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to download the puzzle: {}", e);
+            process::exit(1);
         }
+    };
+
+    let markdown = match client.puzzle(args.year, args.day) {
+        Ok(markdown) => markdown,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
+            eprintln!("Failed to download the puzzle: {}", e);
             process::exit(1);
         }
+    };
+
+    if let Err(e) = puzzle::save(args.year, args.day, &markdown) {
+        eprintln!("Failed to save the puzzle: {}", e);
+        process::exit(1);
     }
+    println!("{}", puzzle::render(&markdown));
 }
@@ -0,0 +1,145 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{Answers, Check};
+use advent_of_code::{default_year, project_root, puzzle, read_input, Outcome, PartResult};
+use std::{fs, process};
+
+const START_MARKER: &str = "<!--- advent_of_code results start --->";
+const END_MARKER: &str = "<!--- advent_of_code results end --->";
+
+fn timing(result: Option<&PartResult>) -> String {
+    match result.map(|r| &r.outcome) {
+        Some(Outcome::Solved(_)) => format!("{:.2?}", result.unwrap().elapsed),
+        Some(outcome) if outcome.is_failure() => "failed".into(),
+        _ => "-".into(),
+    }
+}
+
+/// One row per day of `year`. A part earns a star once its answer matches the one in `answers`.
+fn table(year: u16) -> String {
+    let mut rows = vec![
+        "| Day | Title | Stars | Part 1 | Part 2 | Solution |".to_string(),
+        "| ---: | --- | --- | ---: | ---: | --- |".to_string(),
+    ];
+
+    for solution in advent_of_code::registry().year(year) {
+        let day = solution.day;
+        eprintln!("Solving day {:02}...", day);
+
+        let results: Vec<PartResult> = match read_input(year, day, None) {
+            Ok(input) => (1..=2)
+                .map(|part| solution.run_catching(part, &input))
+                .collect(),
+            Err(_) => vec![],
+        };
+        let answers = Answers::load(year, day).ok().flatten().unwrap_or_default();
+
+        let stars = results
+            .iter()
+            .filter(|r| Check::new(answers.get(r.part), &r.outcome) == Check::Passed)
+            .count();
+        let title = puzzle::load(year, day)
+            .ok()
+            .flatten()
+            .and_then(|markdown| puzzle::title(&markdown))
+            .unwrap_or_else(|| "-".into());
+
+        rows.push(format!(
+            "| {} | {} | {} | {} | {} | [{:02}.rs](./years/{}/{:02}.rs) |",
+            day,
+            title.replace('|', "\\|"),
+            "⭐".repeat(stars),
+            timing(results.first()),
+            timing(results.get(1)),
+            day,
+            year,
+            day
+        ));
+    }
+
+    rows.join("\n")
+}
+
+/// Replaces everything between the markers with `table`, leaving the rest of `readme` untouched.
+fn update_section(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(START_MARKER)? + START_MARKER.len();
+    let end = start + readme[start..].find(END_MARKER)?;
+
+    Some(format!(
+        "{}\n\n{}\n\n{}",
+        &readme[..start],
+        table,
+        &readme[end..]
+    ))
+}
+
+fn parse_args() -> Result<u16, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(default_year))
+}
+
+fn main() {
+    let year = match parse_args() {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let path = project_root().join("README.md");
+    let readme = match fs::read_to_string(&path) {
+        Ok(readme) => readme,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    let readme = match update_section(&readme, &table(year)) {
+        Some(readme) => readme,
+        None => {
+            eprintln!(
+                "\"{}\" has no results section. Add the lines `{}` and `{}` where the table should go.",
+                path.display(),
+                START_MARKER,
+                END_MARKER
+            );
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = fs::write(&path, readme) {
+        eprintln!("Failed to write \"{}\": {}", path.display(), e);
+        process::exit(1);
+    }
+    println!("🎄 Updated the results table in \"{}\".", path.display());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_section() {
+        let readme = format!(
+            "# Title\n\n{}\nold table\n{}\n\n## Usage\n",
+            START_MARKER, END_MARKER
+        );
+        let updated = update_section(&readme, "| Day |").unwrap();
+
+        assert_eq!(
+            updated,
+            format!(
+                "# Title\n\n{}\n\n| Day |\n\n{}\n\n## Usage\n",
+                START_MARKER, END_MARKER
+            )
+        );
+        assert_eq!(update_section(&updated, "| Day |").unwrap(), updated);
+        assert!(update_section("# Title\n", "| Day |").is_none());
+    }
+}
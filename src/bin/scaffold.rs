@@ -2,113 +2,408 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::vault::encrypted_path;
+use advent_of_code::{default_year, file_path, parse_day, project_root, puzzle, year_dir};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind, Write},
+    ops::RangeInclusive,
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
+const MODULE_TEMPLATE: &str = r###"use advent_of_code::{Solution, SolveError};
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
-}
+pub struct Day{{day_padded}};
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = {{day}};
+    type Input = String;
+    type PartOne = Option<{{answer_type}}>;
+    type PartTwo = Option<{{answer_type}}>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Option<{{answer_type}}> {
+        None
+    }
+
+    fn part_two(input: &Self::Input) -> Option<{{answer_type}}> {
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // expected answers per example: "" is `examples/{{day_padded}}.txt`, "2" is `examples/{{day_padded}}-2.txt`.
+    fn part_one_examples() -> Vec<(&'static str, Option<{{answer_type}}>)> {
+        vec![("", None)]
+    }
+
+    fn part_two_examples() -> Vec<(&'static str, Option<{{answer_type}}>)> {
+        vec![("", None)]
+    }
+
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        for (name, expected) in part_one_examples() {
+            let input = Day{{day_padded}}::parse(&advent_of_code::read_example({{year}}, {{day}}, name)).unwrap();
+            assert_eq!(Day{{day_padded}}::part_one(&input), expected, "example \"{}\"", name);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        for (name, expected) in part_two_examples() {
+            let input = Day{{day_padded}}::parse(&advent_of_code::read_example({{year}}, {{day}}, name)).unwrap();
+            assert_eq!(Day{{day_padded}}::part_two(&input), expected, "example \"{}\"", name);
+        }
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+/// Binary of a day, `cargo run --bin <day>`. Shared by all years, it solves the configured one.
+const BINARY_TEMPLATE: &str = r###"fn main() {
+    advent_of_code::solve!({{day}});
+}
+"###;
+
+/// Project file that replaces the built-in `MODULE_TEMPLATE`, if it exists. Relative to the
+/// project root.
+const TEMPLATE_PATH: &str = "templates/day.rs";
+
+const ANSWER_TYPES: [&str; 5] = ["u32", "u64", "i64", "usize", "String"];
+
+struct Args {
+    days: RangeInclusive<u8>,
+    year: u16,
+    /// Return type of both parts, wrapped in an `Option`.
+    answer_type: String,
+    dry_run: bool,
+    /// Regenerate existing modules. Inputs and examples are never overwritten.
+    force: bool,
+}
+
+/// Parses `13..=25` or `13..26`.
+fn parse_range(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let invalid = || format!("invalid range \"{}\", expected e.g. 13..=25", s);
+
+    let (start, end) = s.split_once("..").ok_or_else(invalid)?;
+    let start = parse_day(start)?;
+    let end = match end.strip_prefix('=') {
+        Some(end) => parse_day(end)?,
+        None => {
+            let end: u8 = end.parse().map_err(|_| invalid())?;
+            parse_day(&end.saturating_sub(1).to_string())?
+        }
+    };
+
+    match start <= end {
+        true => Ok(start..=end),
+        false => Err(invalid()),
+    }
+}
+
+fn parse_answer_type(s: &str) -> Result<String, String> {
+    match ANSWER_TYPES.contains(&s) {
+        true => Ok(s.to_string()),
+        false => Err(format!(
+            "unsupported type \"{}\", expected one of {}",
+            s,
+            ANSWER_TYPES.join(", ")
+        )),
+    }
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(default_year),
+        answer_type: args
+            .opt_value_from_fn("--type", parse_answer_type)?
+            .unwrap_or_else(|| "u32".into()),
+        dry_run: args.contains("--dry-run"),
+        force: args.contains("--force"),
+        days: match args.opt_value_from_fn("--range", parse_range)? {
+            Some(days) => days,
+            None => {
+                let day = args.free_from_fn(parse_day)?;
+                day..=day
+            }
+        },
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+fn load_template() -> Result<String, std::io::Error> {
+    match fs::read_to_string(project_root().join(TEMPLATE_PATH)) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(MODULE_TEMPLATE.to_string()),
+        Err(e) => Err(e),
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn render(template: &str, day: u8, year: u16, title: &str, answer_type: &str) -> String {
+    template
+        .replace("{{day_padded}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+        .replace("{{year}}", &year.to_string())
+        .replace("{{title}}", title)
+        .replace("{{answer_type}}", answer_type)
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FileStatus {
+    Created,
+    /// An existing module was overwritten because of `--force`.
+    Regenerated,
+    /// An existing input, example or binary, which is never overwritten.
+    Present,
+    /// An existing module, kept because `--force` was not passed.
+    Skipped,
+    /// An input that is stored encrypted, an empty placeholder would hide it.
+    Encrypted,
+}
+
+/// Creates `path` with `contents`. An existing file is only replaced with `overwrite`.
+/// With `dry_run`, nothing is written but the status is reported as if it was.
+fn write_file(
+    path: &Path,
+    contents: &str,
+    overwrite: bool,
+    dry_run: bool,
+) -> io::Result<FileStatus> {
+    let exists = path.exists();
+
+    if exists && !overwrite {
+        return Ok(FileStatus::Present);
+    }
+    if dry_run {
+        return Ok(if exists {
+            FileStatus::Regenerated
+        } else {
+            FileStatus::Created
+        });
+    }
+
+    if exists {
+        fs::write(path, contents)?;
+        return Ok(FileStatus::Regenerated);
+    }
+
+    // the first day of a year also creates its directories.
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    // `create_new` keeps a file that appeared in the meantime.
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(mut file) => {
+            file.write_all(contents.as_bytes())?;
+            Ok(FileStatus::Created)
         }
-    };
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(FileStatus::Present),
+        Err(e) => Err(e),
+    }
+}
+
+fn report(kind: &str, path: &Path, status: FileStatus, dry_run: bool) {
+    // files of the project are shown relative to it, as in `years/2022/01.rs`.
+    let path = path.strip_prefix(project_root()).unwrap_or(path).display();
+
+    match (status, dry_run) {
+        (FileStatus::Created, false) => println!("Created {} file \"{}\"", kind, path),
+        (FileStatus::Created, true) => println!("Would create {} file \"{}\"", kind, path),
+        (FileStatus::Regenerated, false) => println!("Regenerated {} file \"{}\"", kind, path),
+        (FileStatus::Regenerated, true) => {
+            println!("Would regenerate {} file \"{}\"", kind, path)
+        }
+        (FileStatus::Present, _) => {
+            println!("Kept {} file \"{}\", it is already present", kind, path)
+        }
+        (FileStatus::Skipped, _) => println!(
+            "Skipped {} file \"{}\", it is already present. Pass --force to regenerate it.",
+            kind, path
+        ),
+        (FileStatus::Encrypted, _) => println!(
+            "Skipped {} file \"{}\", it is stored encrypted next to it",
+            kind, path
+        ),
+    }
+}
 
+/// Returns `false` if a file could not be written.
+fn scaffold(args: &Args, template: &str, day: u8) -> bool {
     let day_padded = format!("{:02}", day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let input_path = file_path(args.year, "inputs", day);
+    let example_path = file_path(args.year, "examples", day);
+    let module_path = year_dir(args.year).join(format!("{}.rs", day_padded));
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
-            process::exit(1);
-        }
+    // the puzzle is usually downloaded after scaffolding, so the title may be empty.
+    let title = match puzzle::load(args.year, day) {
+        Ok(Some(markdown)) => puzzle::title(&markdown).unwrap_or_default(),
+        _ => String::new(),
     };
+    let module = render(template, day, args.year, &title, &args.answer_type);
+    let binary_path = project_root()
+        .join("src/bin")
+        .join(format!("{}.rs", day_padded));
+    let binary = render(BINARY_TEMPLATE, day, args.year, &title, &args.answer_type);
+
+    let files = [
+        ("module", &module_path, module.as_str(), args.force),
+        ("binary", &binary_path, binary.as_str(), false),
+        ("input", &input_path, "", false),
+        ("example", &example_path, "", false),
+    ];
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    let mut ok = true;
+    for (kind, path, contents, overwrite) in files {
+        if kind == "input" && !path.exists() && encrypted_path(args.year, day).exists() {
+            report(kind, path, FileStatus::Encrypted, args.dry_run);
+            continue;
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
+        match write_file(path, contents, overwrite, args.dry_run) {
+            Ok(FileStatus::Present) if kind == "module" => {
+                report(kind, path, FileStatus::Skipped, args.dry_run)
+            }
+            Ok(status) => report(kind, path, status, args.dry_run),
+            Err(e) => {
+                eprintln!(
+                    "Failed to write {} file \"{}\": {}",
+                    kind,
+                    path.display(),
+                    e
+                );
+                ok = false;
+            }
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    // show a single module, a preview of a whole range would not be readable.
+    if args.dry_run && args.days.start() == args.days.end() {
+        println!("---");
+        print!("{}", module);
+    }
+
+    ok
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            process::exit(1);
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to process arguments: {}", e);
             process::exit(1);
         }
-    }
+    };
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
+    let template = match load_template() {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+            eprintln!(
+                "Failed to read template \"{}\": {}",
+                project_root().join(TEMPLATE_PATH).display(),
+                e
+            );
             process::exit(1);
         }
+    };
+
+    // checked once, a placeholder is unknown regardless of the day.
+    let rendered = render(&template, 1, 2015, "", &args.answer_type);
+    if let Some(start) = rendered.find("{{") {
+        let placeholder = rendered[start..].split("}}").next().unwrap_or_default();
+        eprintln!(
+            "Warning: unknown placeholder \"{}}}}}\" in template.",
+            placeholder
+        );
+    }
+
+    let mut ok = true;
+    for day in args.days.clone() {
+        ok &= scaffold(&args, &template, day);
     }
 
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if args.dry_run {
+        println!("🎄 This was a dry run, no files were written.");
+    } else if args.days.start() == args.days.end() {
+        let year_arg = match args.year == default_year() {
+            true => String::new(),
+            false => format!(" --year {}", args.year),
+        };
+        println!(
+            "🎄 Type `cargo solve {:02}{}` to run your solution.",
+            args.days.start(),
+            year_arg
+        );
+    } else {
+        println!(
+            "🎄 Scaffolded days {} to {} of {}.",
+            args.days.start(),
+            args.days.end(),
+            args.year
+        );
+    }
+
+    if !ok {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let module = render(MODULE_TEMPLATE, 7, 2022, "No Space Left On Device", "u64");
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.contains("type PartOne = Option<u64>;"));
+        assert!(module.contains("read_example(2022, 7, name)"));
+        assert!(!module.contains("{{"));
+
+        let binary = render(BINARY_TEMPLATE, 7, 2022, "", "u64");
+        assert!(binary.contains("advent_of_code::solve!(7);"));
+
+        // every type has to compile in the examples, e.g. a `String` can not be moved out of a const.
+        for answer_type in ANSWER_TYPES {
+            let module = render(MODULE_TEMPLATE, 7, 2022, "", answer_type);
+            let examples = format!("Vec<(&'static str, Option<{}>)>", answer_type);
+            assert_eq!(module.matches(&examples).count(), 2, "{}", answer_type);
+            assert!(!module.contains("{{"), "{}", answer_type);
+        }
+
+        let header = render(
+            "// {{year}} day {{day}}: {{title}}",
+            7,
+            2022,
+            "Title",
+            "u32",
+        );
+        assert_eq!(header, "// 2022 day 7: Title");
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("13..=25"), Ok(13..=25));
+        assert_eq!(parse_range("13..26"), Ok(13..=25));
+        assert_eq!(parse_range("7..=7"), Ok(7..=7));
+        assert!(parse_range("13..=26").is_err());
+        assert!(parse_range("0..=3").is_err());
+        assert!(parse_range("5..5").is_err());
+        assert!(parse_range("13").is_err());
+    }
 }
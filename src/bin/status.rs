@@ -0,0 +1,326 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{Answers, Check};
+use advent_of_code::log::{self, Level};
+use advent_of_code::{
+    default_year, file_path, project_root, try_read_file, InputError, Outcome, Registry, ANSI_BOLD,
+    ANSI_RESET,
+};
+use std::collections::HashMap;
+use std::process::Command;
+use std::{env, fs, panic, process};
+
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_DIM: &str = "\x1b[2m";
+
+struct Args {
+    year: u16,
+    /// Don't run the example tests, which needs a build of the tests.
+    skip_tests: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(default_year),
+        skip_tests: args.contains("--skip-tests"),
+    })
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// State of one column of a day in the grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mark {
+    Yes,
+    No,
+    /// Present, but broken: a failing test or part, a wrong answer or an input without a key.
+    Failed,
+    /// Not checked, e.g. tests with `--skip-tests`.
+    Unknown,
+}
+
+impl Mark {
+    fn present(present: bool) -> Self {
+        if present {
+            Mark::Yes
+        } else {
+            Mark::No
+        }
+    }
+
+    fn show(self, letter: char) -> String {
+        match self {
+            Mark::Yes => format!("{}{}{}", ANSI_GREEN, letter, ANSI_RESET),
+            Mark::No => format!("{}·{}", ANSI_DIM, ANSI_RESET),
+            Mark::Failed => format!("{}{}{}", ANSI_RED, letter, ANSI_RESET),
+            Mark::Unknown => format!("{}?{}", ANSI_DIM, ANSI_RESET),
+        }
+    }
+}
+
+struct DayStatus {
+    module: Mark,
+    input: Mark,
+    example: Mark,
+    parts: [Mark; 2],
+    tests: Mark,
+    answers: Mark,
+}
+
+impl DayStatus {
+    fn marks(&self) -> [(char, Mark); 7] {
+        [
+            ('M', self.module),
+            ('I', self.input),
+            ('E', self.example),
+            ('1', self.parts[0]),
+            ('2', self.parts[1]),
+            ('T', self.tests),
+            ('A', self.answers),
+        ]
+    }
+}
+
+fn part_mark(outcome: &Outcome) -> Mark {
+    match outcome {
+        Outcome::Solved(_) => Mark::Yes,
+        outcome if outcome.is_failure() => Mark::Failed,
+        _ => Mark::No,
+    }
+}
+
+/// Checks a day of `year`. Parts are run on the input, or on the example if there is none.
+/// Empty files are the placeholders of `cargo scaffold` and count as missing.
+fn day_status(registry: &Registry, year: u16, day: u8, tests: Mark) -> DayStatus {
+    let solution = registry.get(year, day);
+    let example = fs::read_to_string(file_path(year, "examples", day))
+        .ok()
+        .filter(|example| !example.is_empty());
+    let (input_mark, input) = match try_read_file(year, "inputs", day) {
+        Ok(input) if input.is_empty() => (Mark::No, None),
+        Ok(input) => (Mark::Yes, Some(input)),
+        Err(InputError::Locked(_)) => (Mark::Failed, None),
+        Err(_) => (Mark::No, None),
+    };
+
+    let mut parts = [Mark::No; 2];
+    let mut answers = Mark::No;
+    if let Some(solution) = solution {
+        let outcomes: Vec<Outcome> = match input.as_ref().or(example.as_ref()) {
+            Some(input) => (1..=2)
+                .map(|part| solution.run_catching(part, input).outcome)
+                .collect(),
+            None => vec![],
+        };
+        for (mark, outcome) in parts.iter_mut().zip(&outcomes) {
+            *mark = part_mark(outcome);
+        }
+
+        // recorded answers belong to the input, not the example.
+        let recorded = Answers::load(year, day).ok().flatten();
+        if let (Some(recorded), Some(_)) = (recorded, &input) {
+            let checks: Vec<Check> = outcomes
+                .iter()
+                .zip(1..=2)
+                .filter(|(_, part)| recorded.get(*part).is_some())
+                .map(|(outcome, part)| Check::new(recorded.get(part), outcome))
+                .collect();
+            if checks.iter().any(|check| *check != Check::Passed) {
+                answers = Mark::Failed;
+            } else if !checks.is_empty() {
+                answers = Mark::Yes;
+            }
+        }
+    }
+
+    DayStatus {
+        module: Mark::present(solution.is_some()),
+        input: input_mark,
+        example: Mark::present(example.is_some()),
+        parts,
+        tests: if solution.is_some() { tests } else { Mark::No },
+        answers,
+    }
+}
+
+/// Test results per day from the output of `cargo test`, e.g.
+/// `test days::year2022_day07::tests::test_part_one ... ok`.
+fn parse_test_output(year: u16, output: &str) -> HashMap<u8, Mark> {
+    let prefix = format!("test days::year{}_day", year);
+    let mut days = HashMap::new();
+
+    for line in output.lines() {
+        let Some(rest) = line.strip_prefix(&prefix) else {
+            continue;
+        };
+        let Some(day) = rest.get(..2).and_then(|day| day.parse().ok()) else {
+            continue;
+        };
+        let mark = if line.ends_with(" ok") {
+            Mark::Yes
+        } else if line.ends_with(" FAILED") {
+            Mark::Failed
+        } else {
+            continue;
+        };
+
+        let entry = days.entry(day).or_insert(mark);
+        if mark == Mark::Failed {
+            *entry = Mark::Failed;
+        }
+    }
+
+    days
+}
+
+/// Runs the example tests of `year`, `None` if they could not be built.
+fn run_tests(year: u16) -> Option<HashMap<u8, Mark>> {
+    // prefer the cargo this binary was started with.
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    // modules of the days, as generated by `build.rs`.
+    let filter = format!("year{}_day", year);
+
+    eprintln!("Running the example tests of {}...", year);
+    let output = Command::new(cargo)
+        .current_dir(project_root())
+        .args(["test", "--lib", "--", &filter])
+        .output()
+        .ok()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // failing tests still print their results, a failed build does not.
+    if !output.status.success() && !stdout.contains("test result:") {
+        return None;
+    }
+    Some(parse_test_output(year, &stdout))
+}
+
+fn print_grid(year: u16, days: &[DayStatus]) {
+    println!("{}🎄 Advent of Code {} 🎄{}", ANSI_BOLD, year, ANSI_RESET);
+    println!();
+
+    for (row, week) in days.chunks(5).enumerate() {
+        let cells: Vec<String> = week
+            .iter()
+            .enumerate()
+            .map(|(i, status)| {
+                let marks: String = status
+                    .marks()
+                    .iter()
+                    .map(|(letter, mark)| mark.show(*letter))
+                    .collect();
+                format!("{:2} {}", row * 5 + i + 1, marks)
+            })
+            .collect();
+        println!("  {}", cells.join("   "));
+    }
+
+    let count = |f: fn(&DayStatus) -> Mark| days.iter().filter(|s| f(s) == Mark::Yes).count();
+    let parts = days
+        .iter()
+        .flat_map(|s| s.parts)
+        .filter(|mark| *mark == Mark::Yes)
+        .count();
+
+    println!();
+    println!(
+        "  {} solutions, {} inputs, {} examples, {}/50 parts returning answers",
+        count(|s| s.module),
+        count(|s| s.input),
+        count(|s| s.example),
+        parts
+    );
+    println!(
+        "  {} days passing their tests, {} days matching their recorded answers",
+        count(|s| s.tests),
+        count(|s| s.answers)
+    );
+    println!();
+    let legend: Vec<String> = [
+        ('M', "module"),
+        ('I', "input"),
+        ('E', "example"),
+        ('1', "part 1 returns an answer"),
+        ('2', "part 2 returns an answer"),
+        ('T', "example tests pass"),
+        ('A', "answers match the recorded ones"),
+    ]
+    .iter()
+    .map(|(letter, meaning)| format!("{} {}", Mark::Yes.show(*letter), meaning))
+    .collect();
+    println!("  {}", legend.join(", "));
+    println!(
+        "  {}red{}: failing or unreadable, {}: missing, {}: not checked",
+        ANSI_RED,
+        ANSI_RESET,
+        Mark::No.show(' '),
+        Mark::Unknown.show(' ')
+    );
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => fail(&format!("Failed to process arguments: {}", e)),
+    };
+
+    let tests = if args.skip_tests {
+        None
+    } else {
+        match run_tests(args.year) {
+            Some(tests) => Some(tests),
+            None => fail("Failed to build the tests, run `cargo test` to see why."),
+        }
+    };
+
+    // neither panicking solutions nor their logging should end up in the grid.
+    panic::set_hook(Box::new(|_| {}));
+    log::set_level(Level::Off);
+
+    let registry = advent_of_code::registry();
+    let days: Vec<DayStatus> = (1..=25)
+        .map(|day| {
+            let tests = match &tests {
+                Some(tests) => tests.get(&day).copied().unwrap_or(Mark::No),
+                None => Mark::Unknown,
+            };
+            day_status(&registry, args.year, day, tests)
+        })
+        .collect();
+
+    print_grid(args.year, &days);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_test_output() {
+        let output = "\
+running 4 tests
+test days::year2022_day07::tests::test_part_one ... ok
+test days::year2022_day07::tests::test_part_two ... ok
+test days::year2022_day11::tests::test_part_one ... FAILED
+test days::year2022_day11::tests::test_part_two ... ok
+test days::year2021_day01::tests::test_part_one ... ok
+test days::year2022_day12::tests::test_part_two ... ignored
+
+test result: FAILED. 3 passed; 1 failed; 1 ignored";
+
+        let days = parse_test_output(2022, output);
+        assert_eq!(days.get(&7), Some(&Mark::Yes));
+        assert_eq!(days.get(&11), Some(&Mark::Failed));
+        assert_eq!(days.get(&12), None);
+        assert_eq!(days.get(&1), None);
+    }
+}
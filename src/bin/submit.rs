@@ -0,0 +1,105 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::client::{Client, Verdict};
+use advent_of_code::{default_year, parse_day, parse_part, puzzle, read_input, Outcome};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_fn(parse_day)?,
+        part: args.value_from_fn(["-p", "--part"], parse_part)?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(default_year),
+    })
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => fail(&format!("Failed to process arguments: {}", e)),
+    };
+
+    let solution = match advent_of_code::registry().get(args.year, args.day) {
+        Some(solution) => *solution,
+        None => fail(&format!(
+            "There is no solution for day {:02} of {}.",
+            args.day, args.year
+        )),
+    };
+    let input = match read_input(args.year, args.day, None) {
+        Ok(input) => input,
+        Err(e) => fail(&format!("Failed to read input: {}", e)),
+    };
+
+    let answer = match solution.run(args.part, &input).outcome {
+        Outcome::Solved(answer) => answer,
+        outcome => fail(&format!(
+            "Part {} has no answer to submit: {}",
+            args.part,
+            outcome.error().unwrap_or_else(|| "not solved.".into())
+        )),
+    };
+
+    let mut answers = match Answers::load(args.year, args.day) {
+        Ok(answers) => answers.unwrap_or_default(),
+        Err(e) => fail(&format!("Failed to load answers: {}", e)),
+    };
+
+    if let Some(reason) = answers.reject(args.part, &answer) {
+        fail(&format!("Not submitting: {}", reason));
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => fail(&format!("Failed to submit: {}", e)),
+    };
+
+    println!(
+        "🎄 Submitting \"{}\" for day {:02}, part {}...",
+        answer, args.day, args.part
+    );
+    let verdict = match client.submit(args.year, args.day, args.part, &answer) {
+        Ok(submission) => {
+            println!("{}", puzzle::render(&submission.message));
+            submission.verdict
+        }
+        Err(e) => fail(&format!("Failed to submit: {}", e)),
+    };
+
+    answers.record(args.part, &answer, verdict);
+    if let Err(e) = answers.save(args.year, args.day) {
+        fail(&format!("Failed to save answers: {}", e));
+    }
+
+    println!("---");
+    match verdict {
+        Verdict::Correct => println!(
+            "🎄 Correct! Saved the answer to \"{}\".",
+            Answers::path(args.year, args.day).display()
+        ),
+        Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow => {
+            println!("🎄 Wrong answer, it will not be submitted again.");
+            process::exit(1);
+        }
+        Verdict::TooRecent | Verdict::WrongLevel | Verdict::Unknown => {
+            println!("🎄 The answer was not judged, nothing was recorded.");
+            process::exit(1);
+        }
+    }
+}
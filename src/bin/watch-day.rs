@@ -0,0 +1,112 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    default_year, file_path, parse_day, project_root, year_dir, ANSI_BOLD, ANSI_RESET,
+};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use std::{env, fs, process, thread};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// Passed on to the solution, e.g. `--part 1`.
+    solve_args: Vec<OsString>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_fn(parse_day)?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(default_year),
+        solve_args: args.finish(),
+    })
+}
+
+/// Modification times of `paths`, `None` for files that do not exist (yet).
+fn snapshot(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn cargo(args: &[&str], extra_args: &[OsString]) {
+    // prefer the cargo this binary was started with.
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    let status = Command::new(cargo)
+        .current_dir(project_root())
+        .args(args)
+        .args(extra_args)
+        .status();
+
+    if let Err(e) = status {
+        eprintln!("Failed to run cargo: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run(args: &Args, paths: &[PathBuf]) {
+    let day_padded = format!("{:02}", args.day);
+    let year = args.year.to_string();
+    // module of the day, as generated by `build.rs`.
+    let module = format!("year{}_day{}::", year, day_padded);
+
+    print!("{}", ANSI_CLEAR);
+    println!("{}Testing day {}{}", ANSI_BOLD, day_padded, ANSI_RESET);
+    cargo(&["test", "--quiet", "--lib", "--", &module], &[]);
+
+    println!("{}Solving day {}{}", ANSI_BOLD, day_padded, ANSI_RESET);
+    cargo(
+        &["run", "--quiet", "--", &day_padded, "--year", &year],
+        &args.solve_args,
+    );
+
+    println!("---");
+    println!("🎄 Watching for changes, press Ctrl-C to stop:");
+    for path in paths {
+        println!("  {}", path.display());
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo watch-day 7`");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let paths = [
+        year_dir(args.year).join(format!("{:02}.rs", args.day)),
+        file_path(args.year, "examples", args.day),
+        file_path(args.year, "inputs", args.day),
+    ];
+
+    // polling keeps this free of platform specific file watching.
+    let mut last_run = None;
+    loop {
+        let current = snapshot(&paths);
+        if last_run.as_ref() != Some(&current) {
+            run(&args, &paths);
+            last_run = Some(current);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
@@ -0,0 +1,628 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config::config;
+use std::cell::Cell;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{env, fs, thread};
+
+/// Session cookie, used instead of the session file if set.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides the User-Agent sent with every request.
+pub const USER_AGENT_VAR: &str = "AOC_USER_AGENT";
+/// Overrides the Advent of Code URL, e.g. to run against a local stand-in server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const BASE_URL: &str = "https://adventofcode.com";
+/// Contact sent with requests unless `contact` is set in `aoc.toml`.
+const DEFAULT_CONTACT: &str = "https://github.com/fspoettel/advent-of-code-rust";
+
+/// Minimum time between two requests of a client.
+const RATE_LIMIT: Duration = Duration::from_secs(1);
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// Neither [`SESSION_VAR`] nor the session file at the path, if any, hold a session cookie.
+    NoSession(Option<PathBuf>),
+    /// The session cookie is missing or expired.
+    Unauthorized,
+    /// The puzzle does not exist or is not unlocked yet.
+    NotUnlocked,
+    RateLimited,
+    Status(u16),
+    Transport(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::NoSession(Some(path)) => write!(
+                f,
+                "no session cookie found. Set {} or save it to \"{}\".",
+                SESSION_VAR,
+                path.display()
+            ),
+            ClientError::NoSession(None) => {
+                write!(f, "no session cookie found. Set {}.", SESSION_VAR)
+            }
+            ClientError::Unauthorized => {
+                write!(f, "the session cookie was rejected, it may have expired.")
+            }
+            ClientError::NotUnlocked => write!(f, "the puzzle is not unlocked yet."),
+            ClientError::RateLimited => write!(f, "too many requests, try again later."),
+            ClientError::Status(status) => write!(f, "unexpected response status {}.", status),
+            ClientError::Transport(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Response of Advent of Code to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after a previous wrong answer.
+    TooRecent,
+    /// The part is locked or was already solved.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict from the text of the response to a submitted answer.
+    pub fn parse(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Verdict::Correct
+        } else if response.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if response.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if response.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if response.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if response.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub verdict: Verdict,
+    /// The response as markdown.
+    pub message: String,
+}
+
+/// Client for the Advent of Code website.
+///
+/// Requests of a client are spaced out by at least a second, and identify the template with
+/// their User-Agent, as asked for by the Advent of Code automation guidelines.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    user_agent: String,
+    rate_limit: Duration,
+    last_request: Cell<Option<Instant>>,
+}
+
+/// Like `advent_of_code/0.1.0 (+https://github.com/<you>/advent-of-code)`.
+fn user_agent(contact: &str) -> String {
+    format!(
+        "advent_of_code/{} (+{})",
+        env!("CARGO_PKG_VERSION"),
+        contact
+    )
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+            base_url: BASE_URL.into(),
+            session: session.into(),
+            user_agent: user_agent(DEFAULT_CONTACT),
+            rate_limit: RATE_LIMIT,
+            last_request: Cell::new(None),
+        }
+    }
+
+    /// Reads the session cookie from [`SESSION_VAR`] or the session file, and applies the
+    /// contact of `aoc.toml`, [`USER_AGENT_VAR`] and [`BASE_URL_VAR`].
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session_path = config().session_path();
+        let session = match env::var(SESSION_VAR) {
+            Ok(session) => session,
+            Err(_) => session_path
+                .as_ref()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .unwrap_or_default(),
+        };
+        let session = session.trim();
+        if session.is_empty() {
+            return Err(ClientError::NoSession(session_path));
+        }
+
+        let mut client = Client::new(session);
+        if let Some(contact) = &config().contact {
+            client = client.with_user_agent(user_agent(contact));
+        }
+        if let Ok(user_agent) = env::var(USER_AGENT_VAR) {
+            client = client.with_user_agent(user_agent);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            client = client.with_base_url(base_url);
+        }
+        Ok(client)
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    pub fn with_rate_limit(mut self, rate_limit: Duration) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    /// The puzzle input of `day`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// The description of `day` as markdown. Part two is only included once it is unlocked.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let html = self.get(&format!("/{}/day/{}", year, day))?;
+        Ok(puzzle_markdown(&html))
+    }
+
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, ClientError> {
+        let path = format!("/{}/day/{}/answer", year, day);
+        let request = self.request("POST", &path);
+        let html =
+            read_response(request.send_form(&[("level", &part.to_string()), ("answer", answer)]))?;
+
+        let message = puzzle_markdown(&html);
+        Ok(Submission {
+            verdict: Verdict::parse(&message),
+            message,
+        })
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        read_response(self.request("GET", path).call())
+    }
+
+    /// Waits for the rate limit before building the request.
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        if let Some(last_request) = self.last_request.get() {
+            let wait = self.rate_limit.saturating_sub(last_request.elapsed());
+            thread::sleep(wait);
+        }
+        self.last_request.set(Some(Instant::now()));
+
+        self.agent
+            .request(method, &format!("{}{}", self.base_url, path))
+            .set("User-Agent", &self.user_agent)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string())),
+        // the website answers requests without a valid session with 400.
+        Err(ureq::Error::Status(400 | 401 | 403, _)) => Err(ClientError::Unauthorized),
+        Err(ureq::Error::Status(404, _)) => Err(ClientError::NotUnlocked),
+        Err(ureq::Error::Status(429, _)) => Err(ClientError::RateLimited),
+        Err(ureq::Error::Status(status, _)) => Err(ClientError::Status(status)),
+        Err(ureq::Error::Transport(e)) => Err(ClientError::Transport(e.to_string())),
+    }
+}
+
+/// Year of the latest event: the current year in December, the year before otherwise.
+pub fn latest_year() -> u16 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    latest_year_at(secs)
+}
+
+fn latest_year_at(unix_secs: u64) -> u16 {
+    // civil date from days since the epoch, after Howard Hinnant's `civil_from_days`.
+    let z = unix_secs / 86_400 + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    match month {
+        12 => year as u16,
+        _ => year as u16 - 1,
+    }
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+    Some(&tag[start..end])
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        out += &rest[..start];
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let decoded = entity.and_then(|(name, end)| {
+            let c = match name {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                _ => {
+                    let code = match name.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => name.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out + rest
+}
+
+/// Converts the `<article>` elements of a page to markdown, in the format read by
+/// [`crate::puzzle`]. Everything outside of them, like navigation, is dropped.
+pub fn puzzle_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+
+    let mut in_article = false;
+    let mut in_pre = false;
+    let mut heading_start = None;
+    let mut links = vec![];
+
+    while !rest.is_empty() {
+        let Some(tag) = rest.strip_prefix('<') else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = decode_entities(&rest[..end]);
+            rest = &rest[end..];
+
+            if in_pre {
+                out += &text;
+            } else if in_article && !(text.trim().is_empty() && text.contains('\n')) {
+                // outside of `<pre>`, line breaks are only formatting of the page.
+                out += &text.replace('\n', " ");
+            }
+            continue;
+        };
+
+        let end = tag.find('>').unwrap_or(tag.len());
+        rest = tag.get(end + 1..).unwrap_or_default();
+        let tag = &tag[..end];
+
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if name == "article" {
+            in_article = !closing;
+            out += "\n\n";
+            continue;
+        }
+        if !in_article {
+            continue;
+        }
+
+        match (name.as_str(), closing) {
+            ("h2", false) => heading_start = Some(out.len()),
+            ("h2", true) => {
+                // escaped, so the heading is not read as a rule.
+                if let Some(start) = heading_start.take() {
+                    if out[start..].starts_with("---") {
+                        out.insert(start, '\\');
+                    }
+                }
+                out += "\n----------\n\n";
+            }
+            ("p", true) => out += "\n\n",
+            ("em", _) | ("code", _) if in_pre => {}
+            ("em", _) => out.push('*'),
+            ("code", _) => out.push('`'),
+            ("pre", false) => {
+                in_pre = true;
+                out += "\n```\n";
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out += "```\n\n";
+            }
+            ("li", false) => out += "* ",
+            ("li", true) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default().to_string());
+                out.push('[');
+            }
+            ("a", true) => out += &format!("]({})", links.pop().unwrap_or_default()),
+            _ => {}
+        }
+    }
+
+    let mut out = out.trim().to_string();
+    while out.contains("\n\n\n") {
+        out = out.replace("\n\n\n", "\n\n");
+    }
+    out + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+
+    const PUZZLE: &str = include_str!("../fixtures/puzzle.html");
+    const ANSWER_TOO_LOW: &str = include_str!("../fixtures/answer-too-low.html");
+
+    fn read_request(stream: &mut TcpStream) -> String {
+        let mut reader = BufReader::new(stream);
+        let mut request = String::new();
+        let mut content_length = 0;
+
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                content_length = length.trim().parse().unwrap();
+            }
+            request += &line;
+            if line == "\r\n" {
+                break;
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        request + &String::from_utf8(body).unwrap()
+    }
+
+    /// Serves `responses` in order, one per connection, and returns the base url and the
+    /// requests it received.
+    fn stand_in(responses: Vec<(u16, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let request = read_request(&mut stream);
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Stand-In\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, server)
+    }
+
+    fn client(base_url: &str) -> Client {
+        Client::new("53551on")
+            .with_base_url(base_url)
+            .with_user_agent("test-agent")
+            .with_rate_limit(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_puzzle_markdown() {
+        let markdown = puzzle_markdown(PUZZLE);
+
+        assert!(markdown.starts_with(
+            "\\--- Day 1: Calorie Counting ---\n----------\n\nSanta's reindeer typically eat regular reindeer food, but they need a lot of [magical energy](/2018/day/25) to deliver presents on Christmas."
+        ));
+        assert!(markdown.contains("\n\n* The first Elf is carrying food with `1000`, `2000`, and `3000` Calories, a total of `*6000*` Calories.\n"));
+        assert!(!markdown.contains("Support Advent of Code"));
+
+        assert_eq!(puzzle::title(&markdown), Some("Calorie Counting".into()));
+        assert_eq!(
+            puzzle::code_blocks(&markdown),
+            vec!["1000\n2000\n3000\n\n4000"]
+        );
+        assert_eq!(puzzle::expected_answer(&markdown, 1), Some("24000".into()));
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp;&#39;&#x41; & c"),
+            "a <b> &'A & c"
+        );
+    }
+
+    #[test]
+    fn test_user_agent() {
+        let user_agent = user_agent("aoc@example.com");
+        assert!(user_agent.starts_with("advent_of_code/"));
+        assert!(user_agent.ends_with(" (+aoc@example.com)"));
+    }
+
+    #[test]
+    fn test_latest_year() {
+        // 2022-11-30 and 2022-12-01, both at noon.
+        assert_eq!(latest_year_at(1_669_809_600), 2021);
+        assert_eq!(latest_year_at(1_669_896_000), 2022);
+        // 2024-02-29.
+        assert_eq!(latest_year_at(1_709_208_000), 2023);
+    }
+
+    #[test]
+    fn test_client() {
+        let (base_url, server) = stand_in(vec![
+            (200, "1000\n2000\n"),
+            (200, PUZZLE),
+            (200, ANSWER_TOO_LOW),
+        ]);
+        let client = client(&base_url);
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+        assert!(client.puzzle(2022, 1).unwrap().starts_with("\\--- Day 1"));
+
+        let submission = client.submit(2022, 3, 2, "1234").unwrap();
+        assert_eq!(submission.verdict, Verdict::TooLow);
+        assert!(submission
+            .message
+            .starts_with("That's not the right answer"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[1].starts_with("GET /2022/day/1 HTTP/1.1\r\n"));
+        assert!(requests[2].starts_with("POST /2022/day/3/answer HTTP/1.1\r\n"));
+        assert!(requests[2].ends_with("\r\n\r\nlevel=2&answer=1234"));
+
+        for request in requests {
+            let request = request.to_ascii_lowercase();
+            assert!(request.contains("\r\ncookie: session=53551on\r\n"));
+            assert!(request.contains("\r\nuser-agent: test-agent\r\n"));
+        }
+    }
+
+    #[test]
+    fn test_client_errors() {
+        let (base_url, server) = stand_in(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (429, ""),
+            (500, ""),
+        ]);
+        let client = client(&base_url);
+
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Unauthorized)
+        ));
+        assert!(matches!(
+            client.input(2022, 25),
+            Err(ClientError::NotUnlocked)
+        ));
+        assert!(matches!(
+            client.puzzle(2022, 1),
+            Err(ClientError::RateLimited)
+        ));
+        assert!(matches!(
+            client.puzzle(2022, 1),
+            Err(ClientError::Status(500))
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let (base_url, server) = stand_in(vec![(200, ""), (200, "")]);
+        let client = client(&base_url).with_rate_limit(Duration::from_millis(200));
+
+        let started = Instant::now();
+        client.input(2022, 1).unwrap();
+        client.input(2022, 1).unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(
+            Verdict::parse("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer. If you're stuck, ..."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently; you have to wait."),
+            Verdict::TooRecent
+        );
+        assert_eq!(Verdict::parse(""), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,231 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{project_root, BenchConfig, Format};
+use serde::Deserialize;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::{env, fs, io, process};
+
+/// Name of the configuration file in the project root.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+pub enum ConfigError {
+    IoError(io::Error),
+    ParseError(toml::de::Error),
+    /// A setting that parsed, but can not be used, e.g. a negative time.
+    InvalidSetting(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::IoError(e) => write!(f, "could not read the file: {}", e),
+            ConfigError::ParseError(e) => write!(f, "could not parse the file: {}", e),
+            ConfigError::InvalidSetting(e) => write!(f, "invalid setting: {}", e),
+        }
+    }
+}
+
+/// Project settings from `aoc.toml`. Every setting is optional, command line flags take
+/// precedence over them.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Year used when no `--year` is passed.
+    pub year: Option<u16>,
+    /// File holding the session cookie, `~/.adventofcode.session` if not set.
+    pub session_file: Option<String>,
+    /// Contact details sent in the User-Agent of requests to Advent of Code, e.g. the URL of
+    /// this repository or an email address.
+    pub contact: Option<String>,
+    /// Output format of `cargo solve` and `cargo all`.
+    pub format: Format,
+    pub paths: Paths,
+    pub bench: Bench,
+}
+
+/// Directories of the files of a year. `{year}` is replaced with the year, relative paths are
+/// resolved against the project root and `~` against the home directory.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Paths {
+    pub inputs: String,
+    pub examples: String,
+    pub puzzles: String,
+    pub answers: String,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Paths {
+            inputs: "years/{year}/inputs".into(),
+            examples: "years/{year}/examples".into(),
+            puzzles: "years/{year}/puzzles".into(),
+            answers: "years/{year}/answers".into(),
+        }
+    }
+}
+
+impl Paths {
+    /// Directory of `folder` (`inputs`, `examples`, `puzzles` or `answers`) for `year`. Other
+    /// folders are placed in `years/<year>`.
+    pub fn dir(&self, folder: &str, year: u16) -> PathBuf {
+        let template = match folder {
+            "inputs" => &self.inputs,
+            "examples" => &self.examples,
+            "puzzles" => &self.puzzles,
+            "answers" => &self.answers,
+            folder => return resolve(&format!("years/{}/{}", year, folder)),
+        };
+        resolve(&template.replace("{year}", &year.to_string()))
+    }
+}
+
+/// Defaults of `--bench`, in seconds.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Bench {
+    pub warmup: Option<f64>,
+    /// Time spent measuring, like `--bench-time`.
+    pub time: Option<f64>,
+    /// Exact number of measured runs, like `--iterations`.
+    pub iterations: Option<u32>,
+}
+
+impl Bench {
+    fn validate(&self) -> Result<(), ConfigError> {
+        for (name, seconds) in [("warmup", self.warmup), ("time", self.time)] {
+            match seconds {
+                Some(s) if !(s.is_finite() && s >= 0.0) => {
+                    return Err(ConfigError::InvalidSetting(format!(
+                        "bench.{} must be a non-negative number of seconds, not {}",
+                        name, s
+                    )));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_bench_config(&self) -> BenchConfig {
+        let default = BenchConfig::default();
+        BenchConfig {
+            warmup: self.warmup.map_or(default.warmup, Duration::from_secs_f64),
+            budget: self.time.map_or(default.budget, Duration::from_secs_f64),
+            iterations: self.iterations,
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> PathBuf {
+        project_root().join(CONFIG_FILE)
+    }
+
+    /// Reads the config at `path`, the defaults if there is none.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Config::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(ConfigError::IoError(e)),
+        }
+    }
+
+    fn parse(contents: &str) -> Result<Self, ConfigError> {
+        let config: Config = toml::from_str(contents).map_err(ConfigError::ParseError)?;
+        config.bench.validate()?;
+        Ok(config)
+    }
+
+    pub fn session_path(&self) -> Option<PathBuf> {
+        match &self.session_file {
+            Some(path) => Some(resolve(path)),
+            None => home_dir().map(|home| home.join(".adventofcode.session")),
+        }
+    }
+}
+
+/// The project's `aoc.toml`, read once. Exits if it is invalid, as no command should silently
+/// ignore its settings.
+pub fn config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| match Config::load(&Config::path()) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", Config::path().display(), e);
+            process::exit(1);
+        }
+    })
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+fn resolve(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => home_dir().unwrap_or_default().join(rest),
+        None => project_root().join(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config: Config = toml::from_str(
+            r#"
+            year = 2021
+            contact = "aoc@example.com"
+            format = "json"
+
+            [paths]
+            inputs = "/data/aoc/{year}"
+
+            [bench]
+            time = 0.5
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2021));
+        assert_eq!(config.contact.as_deref(), Some("aoc@example.com"));
+        assert_eq!(config.format, Format::Json);
+        assert_eq!(
+            config.paths.dir("inputs", 2021),
+            PathBuf::from("/data/aoc/2021")
+        );
+        assert_eq!(
+            config.paths.dir("examples", 2021),
+            project_root().join("years/2021/examples")
+        );
+        assert_eq!(
+            config.bench.to_bench_config(),
+            BenchConfig {
+                budget: Duration::from_millis(500),
+                ..BenchConfig::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_config_errors() {
+        assert_eq!(toml::from_str::<Config>("").unwrap(), Config::default());
+        assert!(toml::from_str::<Config>("yaer = 2021").is_err());
+        assert!(toml::from_str::<Config>("format = \"xml\"").is_err());
+
+        assert!(Config::parse("[bench]\ntime = 0.5").is_ok());
+        assert!(Config::parse("[bench]\ntime = -1").is_err());
+        assert!(Config::parse("[bench]\nwarmup = nan").is_err());
+        assert!(Config::parse("[bench]\nwarmup = inf").is_err());
+    }
+}
@@ -0,0 +1,283 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::memory::Allocations;
+use crate::{Outcome, PartResult, Stats};
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use std::{env, fs, thread};
+
+/// Hidden argument of the runner that solves a single part, given as `<day>:<part>`, and prints
+/// its result with [`write_result`]. Used by [`run_guarded`].
+pub const RUN_PART_ARG: &str = "--run-part";
+
+/// Separates the result from anything the solution printed itself.
+const RESULT_MARKER: &str = "--- advent_of_code part result ---";
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Limits for running a single part, see [`run_guarded`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock time a part may take.
+    pub timeout: Option<Duration>,
+    /// Peak resident memory in bytes. Only enforced on Linux.
+    pub max_memory: Option<u64>,
+}
+
+/// Parses a positive, finite number of seconds, e.g. `2.5`.
+pub(crate) fn parse_seconds(s: &str) -> Result<Duration, String> {
+    match s.parse::<f64>() {
+        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(Duration::from_secs_f64(seconds)),
+        _ => Err(format!("invalid number of seconds \"{}\"", s)),
+    }
+}
+
+impl Limits {
+    /// Reads `--timeout <seconds>` and `--max-memory <MB>`.
+    /// Returns `None` unless a limit was set.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let timeout = args.opt_value_from_fn("--timeout", parse_seconds)?;
+        let max_memory: Option<u64> = args.opt_value_from_str("--max-memory")?;
+
+        if timeout.is_none() && max_memory.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(Limits {
+            timeout,
+            max_memory: max_memory.map(|mb| mb * 1024 * 1024),
+        }))
+    }
+
+    fn exceeded_by(&self, started: Instant, pid: u32) -> Option<Outcome> {
+        if let Some(timeout) = self.timeout {
+            if started.elapsed() >= timeout {
+                return Some(Outcome::TimedOut(timeout));
+            }
+        }
+
+        if let Some(max_memory) = self.max_memory {
+            if peak_memory(pid).is_some_and(|peak| peak > max_memory) {
+                return Some(Outcome::OutOfMemory(max_memory));
+            }
+        }
+
+        None
+    }
+}
+
+/// Peak resident memory of process `pid` in bytes. Only available on Linux.
+fn peak_memory(pid: u32) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let line = status.lines().find(|l| l.starts_with("VmHWM:"))?;
+    let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+/// Runs `part` of `day` in a child process of the runner, which is killed once it exceeds
+/// `limits`. Unlike a thread, a killed process does not keep running in the background.
+///
+/// The child is started with the runner's own arguments, so it benchmarks the part the same way
+/// the runner would. `stdin` is passed on to the child, as an input read from stdin with
+/// `--input -` is already consumed by the runner.
+pub fn run_guarded(
+    limits: &Limits,
+    day: u8,
+    part: u8,
+    stdin: Option<&str>,
+) -> (PartResult, Option<Stats>) {
+    let aborted = |outcome| (PartResult::aborted(day, part, outcome), None);
+
+    let child = env::current_exe().and_then(|exe| {
+        Command::new(exe)
+            .args(env::args_os().skip(1))
+            .arg(RUN_PART_ARG)
+            .arg(format!("{}:{}", day, part))
+            .stdin(if stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::inherit()
+            })
+            .stdout(Stdio::piped())
+            .spawn()
+    });
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => return aborted(Outcome::Failed(format!("could not start part: {}", e))),
+    };
+
+    // write on another thread as well, an input larger than the pipe blocks until it is read.
+    if let (Some(mut pipe), Some(stdin)) = (child.stdin.take(), stdin) {
+        let stdin = stdin.to_string();
+        thread::spawn(move || pipe.write_all(stdin.as_bytes()));
+    }
+
+    // read on another thread, the child blocks once the pipe is full.
+    let mut stdout = child.stdout.take().unwrap();
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => {}
+            Err(e) => return aborted(Outcome::Failed(format!("could not wait for part: {}", e))),
+        }
+
+        if let Some(outcome) = limits.exceeded_by(started, child.id()) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return aborted(outcome);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    let output = reader.join().ok().and_then(Result::ok).unwrap_or_default();
+    match parse_result(day, part, &output) {
+        Some(result) => result,
+        None => aborted(Outcome::Failed(format!("part exited with {}", status))),
+    }
+}
+
+/// Prints the result of a part in the child process, for [`run_guarded`] to read.
+pub fn write_result(result: &PartResult, stats: Option<&Stats>) {
+    let (status, payload) = match &result.outcome {
+        Outcome::Solved(answer) => ("solved", answer.as_str()),
+        Outcome::Failed(e) => ("failed", e.as_str()),
+        Outcome::Panicked => ("panicked", ""),
+        _ => ("unsolved", ""),
+    };
+    let stats = match stats {
+        Some(s) => format!(
+            "{} {} {} {} {}",
+            s.samples,
+            s.min.as_nanos(),
+            s.median.as_nanos(),
+            s.mean.as_nanos(),
+            s.stddev.as_nanos()
+        ),
+        None => "-".to_string(),
+    };
+    let allocations = match &result.allocations {
+        Some(a) => format!("{} {} {}", a.count, a.bytes, a.peak),
+        None => "-".to_string(),
+    };
+
+    println!("{}", RESULT_MARKER);
+    println!("{} {}", status, result.elapsed.as_nanos());
+    println!("{}", stats);
+    println!("{}", allocations);
+    print!("{}", payload);
+}
+
+fn parse_result(day: u8, part: u8, output: &str) -> Option<(PartResult, Option<Stats>)> {
+    let (_, output) = output.rsplit_once(RESULT_MARKER)?;
+    let mut lines = output.strip_prefix('\n')?.splitn(4, '\n');
+
+    let (status, elapsed) = lines.next()?.split_once(' ')?;
+    let stats = lines.next()?;
+    let allocations = lines.next()?;
+    let payload = lines.next().unwrap_or_default().to_string();
+
+    let outcome = match status {
+        "solved" => Outcome::Solved(payload),
+        "failed" => Outcome::Failed(payload),
+        "panicked" => Outcome::Panicked,
+        "unsolved" => Outcome::Unsolved,
+        _ => return None,
+    };
+
+    let stats = match stats {
+        "-" => None,
+        stats => {
+            let values: Vec<u64> = stats
+                .split(' ')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .ok()?;
+            match values[..] {
+                [samples, min, median, mean, stddev] => Some(Stats {
+                    samples: samples as usize,
+                    min: Duration::from_nanos(min),
+                    median: Duration::from_nanos(median),
+                    mean: Duration::from_nanos(mean),
+                    stddev: Duration::from_nanos(stddev),
+                }),
+                _ => return None,
+            }
+        }
+    };
+
+    let allocations = match allocations {
+        "-" => None,
+        allocations => {
+            let values: Vec<u64> = allocations
+                .split(' ')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .ok()?;
+            match values[..] {
+                [count, bytes, peak] => Some(Allocations { count, bytes, peak }),
+                _ => return None,
+            }
+        }
+    };
+
+    let result = PartResult {
+        day,
+        part,
+        outcome,
+        elapsed: Duration::from_nanos(elapsed.parse().ok()?),
+        allocations,
+    };
+    Some((result, stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_seconds() {
+        assert_eq!(parse_seconds("2.5"), Ok(Duration::from_millis(2500)));
+        assert!(parse_seconds("0").is_err());
+        assert!(parse_seconds("-1").is_err());
+        assert!(parse_seconds("soon").is_err());
+    }
+
+    #[test]
+    fn test_parse_result() {
+        let output = format!(
+            "debug output\n{}\nsolved 1500\n3 1000 1200 1300 50\n4 2048 1024\n##..\n..##",
+            RESULT_MARKER
+        );
+        let (result, stats) = parse_result(10, 2, &output).unwrap();
+
+        assert_eq!(result.outcome, Outcome::Solved("##..\n..##".into()));
+        assert_eq!(result.elapsed, Duration::from_nanos(1500));
+        assert_eq!(stats.unwrap().median, Duration::from_nanos(1200));
+        assert_eq!(
+            result.allocations,
+            Some(Allocations {
+                count: 4,
+                bytes: 2048,
+                peak: 1024
+            })
+        );
+
+        let output = format!("{}\nfailed 20\n-\n-\nline 1: bad input", RESULT_MARKER);
+        let (result, stats) = parse_result(10, 1, &output).unwrap();
+        assert_eq!(result.outcome, Outcome::Failed("line 1: bad input".into()));
+        assert!(stats.is_none());
+        assert!(result.allocations.is_none());
+
+        assert!(parse_result(10, 1, "thread 'main' has overflowed its stack").is_none());
+    }
+}
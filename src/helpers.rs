@@ -5,12 +5,189 @@
 
 use itertools::Itertools;
 use num::Num;
+use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
 
 pub fn strip_newline(in_str: &str) -> String {
     in_str.replace('\r', "")
 }
 
+/// Error pointing at the position in the input where parsing went wrong.
+/// Displays the offending line with the column marked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in chars.
+    pub column: usize,
+    /// The full offending line.
+    pub text: String,
+    /// What was expected at `column`, e.g. `a number` or `"move"`.
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// The token starting at `column`, or `None` at the end of the line.
+    pub fn found(&self) -> Option<&str> {
+        let start = self
+            .text
+            .char_indices()
+            .nth(self.column.saturating_sub(1))
+            .map(|(i, _)| i)?;
+        self.text[start..].split_whitespace().next()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let found = match self.found() {
+            Some(token) => format!("\"{}\"", token),
+            None => "end of line".to_string(),
+        };
+        writeln!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, found
+        )?;
+
+        let gutter = self.line.to_string().len();
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{:gutter$} | {:column$}^",
+            "",
+            "",
+            gutter = gutter,
+            column = self.column.saturating_sub(1)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of input together with its position, see [`numbered_lines`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column, self.text, expected)
+    }
+
+    /// Error pointing at `token`, which has to be a slice of this line's text.
+    pub fn error_at(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or(0);
+        self.error(self.text[..offset].chars().count() + 1, expected)
+    }
+
+    /// Error pointing just past the end of this line.
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error(self.text.chars().count() + 1, expected)
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        self.text.strip_prefix(prefix).ok_or_else(|| {
+            // point at the first char that differs from `prefix`.
+            let matching = self
+                .text
+                .chars()
+                .zip(prefix.chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            self.error(matching + 1, format!("\"{}\"", prefix.trim()))
+        })
+    }
+
+    /// Parses `token`, a slice of this line's text.
+    pub fn parse_at<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error_at(token, expected))
+    }
+
+    pub fn tokens(&self) -> Tokens<'a> {
+        Tokens {
+            line: *self,
+            rest: self.text,
+        }
+    }
+}
+
+/// Lines of `input` numbered from 1.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+/// Whitespace separated tokens of a [`Line`], for parsing lines with a fixed structure.
+#[derive(Clone, Debug)]
+pub struct Tokens<'a> {
+    line: Line<'a>,
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    /// Consumes the next token, which has to be `word`.
+    pub fn expect(&mut self, word: &str) -> Result<(), ParseError> {
+        let expected = format!("\"{}\"", word);
+        match self.next() {
+            Some(token) if token == word => Ok(()),
+            Some(token) => Err(self.line.error_at(token, expected)),
+            None => Err(self.line.error_at_end(expected)),
+        }
+    }
+
+    /// Parses the next token.
+    pub fn parse<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        match self.next() {
+            Some(token) => self.line.parse_at(token, expected),
+            None => Err(self.line.error_at_end(expected)),
+        }
+    }
+
+    /// Fails if there are tokens left.
+    pub fn finish(mut self) -> Result<(), ParseError> {
+        match self.next() {
+            Some(token) => Err(self.line.error_at(token, "end of line")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let trimmed = self.rest.trim_start();
+        if trimmed.is_empty() {
+            self.rest = trimmed;
+            return None;
+        }
+
+        let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        let (token, rest) = trimmed.split_at(end);
+        self.rest = rest;
+        Some(token)
+    }
+}
+
 // I know that with a fixed size arrays would probably be better but vecs mean I don't have to think
 // too hard about the compile time lengths
 #[derive(Clone, PartialEq, Eq)]
@@ -75,7 +252,7 @@ impl<T: Clone> Vec2d<T> {
         self.vec.get_mut(position.1 * self.x + position.0)
     }
 
-    pub fn backing_iter(&self) -> std::slice::Iter<T> {
+    pub fn backing_iter(&self) -> std::slice::Iter<'_, T> {
         self.vec.iter()
     }
 
@@ -100,8 +277,8 @@ impl<T: Clone> Vec2d<T> {
         self.y = new_y;
     }
 
-    pub fn replace_vec(&mut self, vec: &Vec<T>) {
-        self.vec = vec.clone();
+    pub fn replace_vec(&mut self, vec: &[T]) {
+        self.vec = vec.to_vec();
     }
 }
 
@@ -159,7 +336,7 @@ pub fn display_bool_grid(grid: &Vec2d<bool>) -> String {
         .iter()
         .map(|x| {
             let display = if *x { "X" } else { "." };
-            format!("{display}")
+            display.to_string()
         })
         .collect();
 
@@ -221,3 +398,55 @@ impl<T: Clone + Debug> Iterator for DirectionIter<'_, T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_display() {
+        let line = numbered_lines("move 1 from 2 to 1\nmove x from 1 to 2")
+            .nth(1)
+            .unwrap();
+        let error = line.tokens().nth(1).map(|t| line.error_at(t, "a number"));
+
+        assert_eq!(
+            error.unwrap().to_string(),
+            "line 2, column 6: expected a number, found \"x\"\n2 | move x from 1 to 2\n  |      ^"
+        );
+    }
+
+    #[test]
+    fn test_tokens() {
+        let line = Line {
+            number: 7,
+            text: "move 3 from 1",
+        };
+
+        let mut tokens = line.tokens();
+        assert_eq!(tokens.expect("move"), Ok(()));
+        assert_eq!(tokens.parse::<u32>("a number"), Ok(3));
+        assert_eq!(
+            tokens.expect("to"),
+            Err(ParseError::new(7, 8, "move 3 from 1", "\"to\""))
+        );
+        assert_eq!(tokens.parse::<u32>("a number"), Ok(1));
+
+        let error = tokens.parse::<u32>("a number").unwrap_err();
+        assert_eq!((error.column, error.found()), (14, None));
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        let line = Line {
+            number: 2,
+            text: "  Starting itms: 79, 98",
+        };
+
+        assert_eq!(line.strip_prefix("  Starting"), Ok(" itms: 79, 98"));
+        assert_eq!(
+            line.strip_prefix("  Starting items: ").unwrap_err().column,
+            14
+        );
+    }
+}
@@ -0,0 +1,216 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::client::latest_year;
+use crate::config::config;
+use crate::vault::{encrypted_path, Key, VaultError, KEY_VAR};
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, fs};
+
+/// Overrides the directory real puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Overrides the year of `aoc.toml`, when none is passed with `--year`.
+pub const YEAR_VAR: &str = "AOC_YEAR";
+
+pub enum InputError {
+    NotFound(PathBuf),
+    IoError(PathBuf, io::Error),
+    StdinError(io::Error),
+    /// Only an encrypted copy of the input exists, and there is no key to decrypt it.
+    Locked(PathBuf),
+    VaultError(PathBuf, VaultError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "\"{}\" does not exist.", path.display()),
+            InputError::IoError(path, e) => {
+                write!(f, "could not read \"{}\": {}", path.display(), e)
+            }
+            InputError::StdinError(e) => write!(f, "could not read from stdin: {}", e),
+            InputError::Locked(path) => write!(
+                f,
+                "\"{}\" can not be decrypted without a key. Set {} or add the key file.",
+                path.display(),
+                KEY_VAR
+            ),
+            InputError::VaultError(path, e) => write!(f, "\"{}\": {}", path.display(), e),
+        }
+    }
+}
+
+/// Where a day binary reads its input from, see `--input`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("input path must not be empty".into()),
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::File(PathBuf::from(path))),
+        }
+    }
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => read_path(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::StdinError)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn read_path(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_path_buf()),
+        _ => InputError::IoError(path.to_path_buf(), e),
+    })
+}
+
+/// Root of this crate. Used instead of the working directory, so binaries can be run from anywhere.
+pub fn project_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The year set with [`YEAR_VAR`] or in `aoc.toml`, otherwise the latest event.
+pub fn default_year() -> u16 {
+    env::var(YEAR_VAR)
+        .ok()
+        .and_then(|year| year.trim().parse().ok())
+        .or(config().year)
+        .unwrap_or_else(latest_year)
+}
+
+/// Directory holding the solutions of `year`, and by default its other files as well.
+pub fn year_dir(year: u16) -> PathBuf {
+    project_root().join("years").join(year.to_string())
+}
+
+/// Path of `<day>.txt` in the `folder` of `year`, see `[paths]` in `aoc.toml`. For `inputs`,
+/// the directory can be overridden with [`INPUT_DIR_VAR`].
+pub fn file_path(year: u16, folder: &str, day: u8) -> PathBuf {
+    let file_name = format!("{:02}.txt", day);
+
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) if folder == "inputs" => PathBuf::from(dir).join(file_name),
+        _ => config().paths.dir(folder, year).join(file_name),
+    }
+}
+
+/// Path of an example of `day`: `examples/<day>.txt` for `""`, `examples/<day>-<name>.txt` for
+/// other names.
+pub fn example_path(year: u16, day: u8, name: &str) -> PathBuf {
+    let path = file_path(year, "examples", day);
+    match name {
+        "" => path,
+        name => path.with_file_name(format!("{:02}-{}.txt", day, name)),
+    }
+}
+
+/// Reads the example `name` of `day`, see [`example_path`].
+pub fn read_example(year: u16, day: u8, name: &str) -> String {
+    match read_path(&example_path(year, day, name)) {
+        Ok(contents) => contents,
+        Err(e) => panic!("could not open example file: {}", e),
+    }
+}
+
+pub fn read_file(year: u16, folder: &str, day: u8) -> String {
+    match try_read_file(year, folder, day) {
+        Ok(contents) => contents,
+        Err(e) => panic!("could not open input file: {}", e),
+    }
+}
+
+/// Inputs that are missing or empty, like the placeholders of `cargo scaffold`, are decrypted
+/// from their encrypted copy, if there is one.
+pub fn try_read_file(year: u16, folder: &str, day: u8) -> Result<String, InputError> {
+    match read_path(&file_path(year, folder, day)) {
+        Err(InputError::NotFound(path)) if folder == "inputs" => {
+            read_encrypted(year, day).unwrap_or(Err(InputError::NotFound(path)))
+        }
+        Ok(input) if input.is_empty() && folder == "inputs" => {
+            read_encrypted(year, day).unwrap_or(Ok(input))
+        }
+        result => result,
+    }
+}
+
+/// Decrypts the input of `day`, `None` if it has no encrypted copy.
+fn read_encrypted(year: u16, day: u8) -> Option<Result<String, InputError>> {
+    let path = encrypted_path(year, day);
+    let data = fs::read(&path).ok()?;
+
+    let input = match Key::load() {
+        Ok(Some(key)) => key
+            .decrypt(&data)
+            .map_err(|e| InputError::VaultError(path, e)),
+        Ok(None) => Err(InputError::Locked(path)),
+        Err(e) => Err(InputError::VaultError(path, e)),
+    };
+    Some(input)
+}
+
+/// Reads the real input of `day`, from `source` if given.
+pub fn read_input(year: u16, day: u8, source: Option<&InputSource>) -> Result<String, InputError> {
+    match source {
+        Some(source) => source.read(),
+        None => try_read_file(year, "inputs", day),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input_source() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "stress/08.txt".parse(),
+            Ok(InputSource::File(PathBuf::from("stress/08.txt")))
+        );
+        assert!("".parse::<InputSource>().is_err());
+    }
+
+    #[test]
+    fn test_read_file() {
+        assert!(file_path(2022, "examples", 1).ends_with("years/2022/examples/01.txt"));
+        assert!(try_read_file(2022, "examples", 1).is_ok());
+        assert!(try_read_file(2021, "examples", 1).is_err());
+        assert_eq!(example_path(2022, 1, ""), file_path(2022, "examples", 1));
+        assert!(example_path(2022, 9, "2").ends_with("years/2022/examples/09-2.txt"));
+        assert_eq!(read_example(2022, 1, ""), read_file(2022, "examples", 1));
+
+        let source = InputSource::File(project_root().join("years/2022/examples/01.txt"));
+        assert_eq!(
+            read_input(2022, 1, Some(&source)).ok(),
+            try_read_file(2022, "examples", 1).ok()
+        );
+
+        let missing = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        assert!(matches!(
+            read_input(2022, 1, Some(&missing)),
+            Err(InputError::NotFound(_))
+        ));
+    }
+}
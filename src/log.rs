@@ -0,0 +1,171 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Environment variable setting the log level, used unless the runner is passed `-v` or `-q`.
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// How much solutions log with [`info!`](crate::info), [`debug!`](crate::debug) and
+/// [`trace!`](crate::trace). Each level includes the ones before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    /// Summaries, e.g. intermediate totals.
+    Info,
+    /// Intermediate results, e.g. counts or paths.
+    Debug,
+    /// Anything large, e.g. whole grids.
+    Trace,
+}
+
+const LEVELS: [Level; 4] = [Level::Off, Level::Info, Level::Debug, Level::Trace];
+
+/// Not set yet, read from [`LOG_VAR`] on first use.
+const UNSET: u8 = u8::MAX;
+
+static LEVEL: AtomicU8 = AtomicU8::new(UNSET);
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "off" | "0" => Ok(Level::Off),
+            "info" | "1" => Ok(Level::Info),
+            "debug" | "2" => Ok(Level::Debug),
+            "trace" | "3" => Ok(Level::Trace),
+            _ => Err(format!(
+                "unknown log level \"{}\", expected one of: off, info, debug, trace",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Off => "off",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Level {
+    /// Reads `-v` (info), `-vv` (debug), `-vvv` (trace) or `-q/--quiet` (off). Repeated `-v`s add
+    /// up, `--quiet` wins over all of them. Returns `None` if none was passed.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Option<Self> {
+        let mut verbosity = 0;
+        for (flag, count) in [("-vvv", 3), ("-vv", 2), ("-v", 1), ("--verbose", 1)] {
+            while args.contains(flag) {
+                verbosity += count;
+            }
+        }
+
+        if args.contains(["-q", "--quiet"]) {
+            return Some(Level::Off);
+        }
+
+        (verbosity > 0).then(|| LEVELS[verbosity.min(LEVELS.len() - 1)])
+    }
+}
+
+/// The current log level: the one set with [`set_level`], otherwise [`LOG_VAR`], otherwise off.
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        UNSET => {
+            let level = env::var(LOG_VAR)
+                .ok()
+                .and_then(|level| level.parse().ok())
+                .unwrap_or(Level::Off);
+            // another thread may have set the level in the meantime.
+            let _ =
+                LEVEL.compare_exchange(UNSET, level as u8, Ordering::Relaxed, Ordering::Relaxed);
+            LEVELS[LEVEL.load(Ordering::Relaxed) as usize]
+        }
+        level => LEVELS[level as usize],
+    }
+}
+
+/// Overrides [`LOG_VAR`] for the rest of the process.
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level <= self::level()
+}
+
+/// Writes a log line to stderr, so it never mixes with the answers on stdout. Use the macros
+/// instead, which only format their arguments if `level` is enabled.
+#[doc(hidden)]
+pub fn write(level: Level, module: &str, args: fmt::Arguments) {
+    // e.g. `advent_of_code::year2022_day07`, only the day is of interest.
+    let module = module.rsplit("::").next().unwrap_or(module);
+    eprintln!("[{} {}] {}", level, module, args);
+}
+
+/// Logs at `level` with the formatting of `format!`.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Logs summaries, shown with `-v` or `AOC_LOG=info`.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+/// Logs intermediate results, shown with `-vv` or `AOC_LOG=debug`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+/// Logs anything large, shown with `-vvv` or `AOC_LOG=trace`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsString;
+
+    fn level_from(args: &[&str]) -> Option<Level> {
+        let args = args.iter().map(OsString::from).collect();
+        Level::from_args(&mut pico_args::Arguments::from_vec(args))
+    }
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!("debug".parse(), Ok(Level::Debug));
+        assert_eq!("TRACE".parse(), Ok(Level::Trace));
+        assert_eq!("1".parse(), Ok(Level::Info));
+        assert!("verbose".parse::<Level>().is_err());
+    }
+
+    #[test]
+    fn test_level_from_args() {
+        assert_eq!(level_from(&[]), None);
+        assert_eq!(level_from(&["-v"]), Some(Level::Info));
+        assert_eq!(level_from(&["-vv"]), Some(Level::Debug));
+        assert_eq!(level_from(&["-v", "--verbose"]), Some(Level::Debug));
+        assert_eq!(level_from(&["-vvv", "-v"]), Some(Level::Trace));
+        assert_eq!(level_from(&["-vv", "--quiet"]), Some(Level::Off));
+    }
+}
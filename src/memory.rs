@@ -0,0 +1,186 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// Whether allocations are counted. Enabled with the `count-allocations` feature, which
+/// replaces the global allocator with one that counts. Without it, nothing is counted at all.
+pub const ENABLED: bool = cfg!(feature = "count-allocations");
+
+/// Heap usage of a piece of code, see [`measure`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Allocations {
+    /// Number of allocations, including reallocations.
+    pub count: u64,
+    /// Bytes requested in total. A growing reallocation only adds the bytes it grew by.
+    pub bytes: u64,
+    /// Highest number of bytes that were live at once, on top of those live before.
+    pub peak: u64,
+}
+
+impl Allocations {
+    /// Like `12 allocs, 3.40 MB allocated, 1.20 MB peak`.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} allocs, {} allocated, {} peak",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.2} {}", value, UNITS[unit])
+    }
+}
+
+/// Runs `f` and returns its heap usage, `None` unless allocations are counted.
+///
+/// The counters are shared by all threads, so allocations of other threads running at the same
+/// time (e.g. with `--jobs`) are included.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let (value, allocations) = counting::measure(f);
+        (value, Some(allocations))
+    }
+
+    #[cfg(not(feature = "count-allocations"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+mod counting {
+    use super::Allocations;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+    static COUNT: AtomicU64 = AtomicU64::new(0);
+    static BYTES: AtomicU64 = AtomicU64::new(0);
+    static LIVE: AtomicU64 = AtomicU64::new(0);
+    static PEAK: AtomicU64 = AtomicU64::new(0);
+
+    /// The system allocator, counting what passes through it.
+    struct Counting;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    fn grow(bytes: u64) {
+        BYTES.fetch_add(bytes, Relaxed);
+        let live = LIVE.fetch_add(bytes, Relaxed) + bytes;
+        PEAK.fetch_max(live, Relaxed);
+    }
+
+    fn shrink(bytes: u64) {
+        LIVE.fetch_sub(bytes, Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                COUNT.fetch_add(1, Relaxed);
+                grow(layout.size() as u64);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                COUNT.fetch_add(1, Relaxed);
+                grow(layout.size() as u64);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            shrink(layout.size() as u64);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                COUNT.fetch_add(1, Relaxed);
+                let (old, new) = (layout.size() as u64, new_size as u64);
+                if new > old {
+                    grow(new - old);
+                } else {
+                    shrink(old - new);
+                }
+            }
+            new_ptr
+        }
+    }
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+        let count = COUNT.load(Relaxed);
+        let bytes = BYTES.load(Relaxed);
+        let live = LIVE.load(Relaxed);
+        PEAK.store(live, Relaxed);
+
+        let value = f();
+
+        let allocations = Allocations {
+            count: COUNT.load(Relaxed) - count,
+            bytes: BYTES.load(Relaxed) - bytes,
+            peak: PEAK.load(Relaxed).saturating_sub(live),
+        };
+        (value, allocations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        let allocations = Allocations {
+            count: 12,
+            bytes: 3_400_000,
+            peak: 512,
+        };
+        assert_eq!(
+            allocations.summary(),
+            "12 allocs, 3.40 MB allocated, 512 B peak"
+        );
+    }
+
+    #[test]
+    fn test_measure() {
+        let (len, allocations) = measure(|| {
+            let v: Vec<u64> = Vec::with_capacity(1000);
+            drop(v);
+            let v: Vec<u8> = vec![1; 100];
+            v.len()
+        });
+
+        assert_eq!(len, 100);
+        if ENABLED {
+            // other tests allocate and free at the same time, so only the totals are reliable.
+            let allocations = allocations.unwrap();
+            assert!(allocations.count >= 2);
+            assert!(allocations.bytes >= 8100);
+        } else {
+            assert!(allocations.is_none());
+        }
+    }
+}
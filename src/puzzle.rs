@@ -0,0 +1,304 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config::config;
+use crate::{ANSI_BOLD, ANSI_RESET};
+use std::path::PathBuf;
+use std::{fs, io};
+
+const ANSI_CYAN: &str = "\x1b[36m";
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+
+/// Path of the puzzle description downloaded by `cargo download`.
+pub fn path(year: u16, day: u8) -> PathBuf {
+    config()
+        .paths
+        .dir("puzzles", year)
+        .join(format!("{:02}.md", day))
+}
+
+/// Returns `None` if the puzzle description of `day` was not downloaded yet.
+pub fn load(year: u16, day: u8) -> io::Result<Option<String>> {
+    match fs::read_to_string(path(year, day)) {
+        Ok(markdown) => Ok(Some(markdown)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Saves a puzzle description to [`path`].
+pub fn save(year: u16, day: u8, markdown: &str) -> io::Result<PathBuf> {
+    let path = path(year, day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, markdown)?;
+    Ok(path)
+}
+
+/// Title of a puzzle, e.g. "Calorie Counting" for `--- Day 1: Calorie Counting ---`.
+pub fn title(markdown: &str) -> Option<String> {
+    markdown.lines().find_map(|line| {
+        let heading = line.trim_start_matches('\\').strip_prefix("--- Day ")?;
+        let (_, title) = heading.strip_suffix(" ---")?.split_once(": ")?;
+        Some(title.to_string())
+    })
+}
+
+fn is_setext_underline(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+/// Renders a puzzle description for the terminal: headings and emphasis in bold, code in cyan,
+/// links as their underlined text.
+pub fn render(markdown: &str) -> String {
+    let mut out = vec![];
+    let mut in_code_block = false;
+    let mut lines = markdown.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            out.push(format!("    {}{}{}", ANSI_CYAN, line, ANSI_RESET));
+            continue;
+        }
+
+        let heading = match line.strip_prefix('#') {
+            Some(heading) => Some(heading.trim_start_matches('#').trim()),
+            None if !line.is_empty() && lines.peek().is_some_and(|l| is_setext_underline(l)) => {
+                lines.next();
+                Some(line)
+            }
+            None => None,
+        };
+
+        if let Some(heading) = heading {
+            out.push(format!(
+                "{}{}{}",
+                ANSI_BOLD,
+                render_inline(heading),
+                ANSI_RESET
+            ));
+        } else if let Some(item) = line.strip_prefix("* ").or(line.strip_prefix("- ")) {
+            out.push(format!("  • {}", render_inline(item)));
+        } else {
+            out.push(render_inline(line));
+        }
+    }
+
+    out.join("\n")
+}
+
+/// Contents of the fenced code blocks in a puzzle description, in order. These hold the examples.
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            match block.take() {
+                Some(mut lines) => {
+                    while lines.last().is_some_and(|l| l.trim().is_empty()) {
+                        lines.pop();
+                    }
+                    blocks.push(lines.join("\n"));
+                }
+                None => block = Some(vec![]),
+            }
+        } else if let Some(lines) = block.as_mut() {
+            lines.push(line);
+        }
+    }
+
+    blocks
+}
+
+/// Guesses the example answer of `part`. Puzzles state it as emphasized code, e.g.
+/// `` *`24000`* ``, usually as the last one in the text of the part.
+pub fn expected_answer(markdown: &str, part: u8) -> Option<String> {
+    let (part_one, part_two) = match markdown.find("--- Part Two ---") {
+        Some(i) => markdown.split_at(i),
+        None => (markdown, ""),
+    };
+    let text = if part == 1 { part_one } else { part_two };
+
+    let mut in_code_block = false;
+    let mut answer = None;
+
+    for line in text.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        // every other segment is inline code.
+        let segments: Vec<&str> = line.split('`').collect();
+        for i in (1..segments.len().saturating_sub(1)).step_by(2) {
+            let code = segments[i];
+            let wrapped = segments[i - 1].ends_with('*') && segments[i + 1].starts_with('*');
+
+            if let Some(inner) = code.strip_prefix('*').and_then(|c| c.strip_suffix('*')) {
+                if !inner.is_empty() {
+                    answer = Some(inner.to_string());
+                }
+            } else if wrapped && !code.is_empty() {
+                answer = Some(code.to_string());
+            }
+        }
+    }
+
+    answer
+}
+
+#[derive(Default)]
+struct InlineStyle {
+    emphasis: bool,
+    code: bool,
+    link: bool,
+}
+
+impl InlineStyle {
+    fn ansi(&self) -> String {
+        let mut ansi = ANSI_RESET.to_string();
+        if self.emphasis {
+            ansi += ANSI_BOLD;
+        }
+        if self.code {
+            ansi += ANSI_CYAN;
+        }
+        if self.link {
+            ansi += ANSI_UNDERLINE;
+        }
+        ansi
+    }
+}
+
+fn render_inline(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut style = InlineStyle::default();
+    let mut out = String::new();
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        match c {
+            '\\' if next.is_some_and(|n| n.is_ascii_punctuation()) => {
+                out.push(next.unwrap());
+                i += 1;
+            }
+            '`' => {
+                style.code = !style.code;
+                out += &style.ansi();
+            }
+            // a lone `*` is a literal, e.g. in "2 * 3".
+            '*' if style.emphasis || next.is_some_and(|n| !n.is_whitespace()) => {
+                style.emphasis = !style.emphasis;
+                out += &style.ansi();
+            }
+            '[' if !style.code => {
+                style.link = true;
+                out += &style.ansi();
+            }
+            ']' if style.link => {
+                style.link = false;
+                out += &style.ansi();
+
+                // drop the target of `[text](target)`.
+                if next == Some('(') {
+                    if let Some(end) = chars[i..].iter().position(|&c| c == ')') {
+                        i += end;
+                    }
+                }
+            }
+            c => out.push(c),
+        }
+
+        i += 1;
+    }
+
+    if style.emphasis || style.code || style.link {
+        out += ANSI_RESET;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut in_escape = false;
+        for c in s.chars() {
+            match c {
+                '\x1b' => in_escape = true,
+                'm' if in_escape => in_escape = false,
+                c if !in_escape => out.push(c),
+                _ => {}
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_render() {
+        let markdown = "\\--- Day 1: Calorie Counting ---\n----------\n\nA *star* costs `2 * 3`, see [this](/2018/day/25).\n\n```\n1000\n*2000*\n```\n\n* The first Elf carries `*6000*` Calories.";
+        let rendered = render(markdown);
+
+        assert_eq!(
+            strip_ansi(&rendered),
+            "--- Day 1: Calorie Counting ---\n\nA star costs 2 * 3, see this.\n\n    1000\n    *2000*\n\n  • The first Elf carries 6000 Calories."
+        );
+        assert!(rendered.starts_with(&format!("{}--- Day 1", ANSI_BOLD)));
+        assert!(rendered.contains(&format!("{}{}star", ANSI_RESET, ANSI_BOLD)));
+    }
+
+    #[test]
+    fn test_render_inline() {
+        assert_eq!(
+            render_inline("*`24000`*"),
+            format!(
+                "{r}{b}{r}{b}{c}24000{r}{b}{r}",
+                r = ANSI_RESET,
+                b = ANSI_BOLD,
+                c = ANSI_CYAN
+            )
+        );
+        assert_eq!(render_inline("a \\* b"), "a * b");
+        assert_eq!(render_inline("2 * 3"), "2 * 3");
+    }
+
+    #[test]
+    fn test_title() {
+        let markdown = "\\--- Day 1: Calorie Counting ---\n----------\n\nSanta's reindeer...";
+        assert_eq!(title(markdown), Some("Calorie Counting".into()));
+        assert_eq!(title("no heading"), None);
+    }
+
+    #[test]
+    fn test_code_blocks() {
+        let markdown = "For example:\n\n```\n1000\n2000\n\n3000\n\n```\n\nAnd:\n\n```\nR 4\n```\n";
+        assert_eq!(code_blocks(markdown), vec!["1000\n2000\n\n3000", "R 4"]);
+    }
+
+    #[test]
+    fn test_expected_answer() {
+        let markdown = "\\--- Day 1: Calorie Counting ---\n----------\n\n```\n*1*\n```\n\n* The first Elf carries `*6000*` Calories.\n\nThis Elf is carrying *`24000`* Calories.\n\n\\--- Part Two ---\n----------\n\nThe sum is `*45000*`, not `4000`.\n";
+
+        assert_eq!(expected_answer(markdown, 1), Some("24000".into()));
+        assert_eq!(expected_answer(markdown, 2), Some("45000".into()));
+        assert_eq!(expected_answer("No answers `here`.", 1), None);
+        assert_eq!(expected_answer("No part two.", 2), None);
+    }
+}
@@ -0,0 +1,424 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::Stats;
+use crate::memory::{self, Allocations};
+use crate::{
+    print_allocations, print_result, Outcome, PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use serde::Deserialize;
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Output format of `cargo solve` and `cargo all`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: text, json, csv",
+                s
+            )),
+        }
+    }
+}
+
+/// One line of machine-readable output: a single part of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Record {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    /// Error message of a failed part.
+    error: Option<String>,
+    /// `None` if the part was not run at all, skipped or aborted by a limit.
+    elapsed: Option<Duration>,
+    status: &'static str,
+    /// `Some` in benchmark mode, even if the part was not benchmarked.
+    stats: Option<Option<Stats>>,
+    /// `Some` if allocations are counted, even if the part was not run. Set by the [`Reporter`].
+    allocations: Option<Option<Allocations>>,
+}
+
+impl Record {
+    fn from_result(result: &PartResult, stats: Option<Option<Stats>>) -> Self {
+        let (answer, status) = match &result.outcome {
+            Outcome::Solved(answer) => (Some(answer.clone()), "solved"),
+            Outcome::Unsolved => (None, "unsolved"),
+            Outcome::Failed(_) => (None, "failed"),
+            Outcome::Panicked => (None, "panicked"),
+            Outcome::Skipped => (None, "skipped"),
+            Outcome::TimedOut(_) => (None, "timed_out"),
+            Outcome::OutOfMemory(_) => (None, "out_of_memory"),
+        };
+
+        // in benchmark mode, the median is more representative than any single run. Parts that
+        // were skipped or aborted have no time of their own.
+        let elapsed = match (&stats, &result.outcome) {
+            (_, Outcome::Skipped | Outcome::TimedOut(_) | Outcome::OutOfMemory(_)) => None,
+            (Some(Some(stats)), _) => Some(stats.median),
+            _ => Some(result.elapsed),
+        };
+
+        Record {
+            day: result.day,
+            part: result.part,
+            answer,
+            error: result.outcome.error(),
+            elapsed,
+            status,
+            stats,
+            allocations: None,
+        }
+    }
+
+    fn not_run(day: u8, part: u8, bench: bool) -> Self {
+        Record {
+            day,
+            part,
+            answer: None,
+            error: None,
+            elapsed: None,
+            status: "unsolved",
+            stats: bench.then_some(None),
+            allocations: None,
+        }
+    }
+
+    /// Benchmark and allocation columns as `(name, value)`, empty unless benchmarking or
+    /// counting allocations.
+    fn stat_fields(&self) -> Vec<(&'static str, Option<u128>)> {
+        let mut fields = vec![];
+        if let Some(stats) = &self.stats {
+            fields.extend([
+                ("samples", stats.as_ref().map(|s| s.samples as u128)),
+                ("min_ns", stats.as_ref().map(|s| s.min.as_nanos())),
+                ("median_ns", stats.as_ref().map(|s| s.median.as_nanos())),
+                ("mean_ns", stats.as_ref().map(|s| s.mean.as_nanos())),
+                ("stddev_ns", stats.as_ref().map(|s| s.stddev.as_nanos())),
+            ]);
+        }
+        if let Some(allocations) = &self.allocations {
+            fields.extend([
+                ("allocations", allocations.map(|a| a.count as u128)),
+                ("allocated_bytes", allocations.map(|a| a.bytes as u128)),
+                ("peak_bytes", allocations.map(|a| a.peak as u128)),
+            ]);
+        }
+        fields
+    }
+
+    fn to_json(&self) -> String {
+        let mut json = format!(
+            "{{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_ns\": {}, \"status\": \"{}\", \"error\": {}",
+            self.day,
+            self.part,
+            self.answer
+                .as_deref()
+                .map_or("null".to_string(), json_string),
+            self.elapsed
+                .map_or("null".to_string(), |e| e.as_nanos().to_string()),
+            self.status,
+            self.error.as_deref().map_or("null".to_string(), json_string)
+        );
+        for (name, value) in self.stat_fields() {
+            let value = value.map_or("null".to_string(), |v| v.to_string());
+            write!(json, ", \"{}\": {}", name, value).unwrap();
+        }
+        json.push('}');
+        json
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = format!(
+            "{},{},{},{},{},{}",
+            self.day,
+            self.part,
+            self.answer.as_deref().map_or(String::new(), csv_field),
+            self.elapsed
+                .map_or(String::new(), |e| e.as_nanos().to_string()),
+            self.status,
+            self.error.as_deref().map_or(String::new(), csv_field)
+        );
+        for (_, value) in self.stat_fields() {
+            write!(csv, ",{}", value.map_or(String::new(), |v| v.to_string())).unwrap();
+        }
+        csv
+    }
+}
+
+const CSV_HEADER: &str = "day,part,answer,elapsed_ns,status,error";
+const CSV_BENCH_HEADER: &str = ",samples,min_ns,median_ns,mean_ns,stddev_ns";
+const CSV_ALLOCATIONS_HEADER: &str = ",allocations,allocated_bytes,peak_bytes";
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn print_bench_result(result: &PartResult, stats: &Stats) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+    if let Outcome::Solved(answer) = &result.outcome {
+        println!(
+            "{} {}(median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, {} runs){}",
+            answer,
+            ANSI_ITALIC,
+            stats.median,
+            stats.mean,
+            stats.stddev,
+            stats.min,
+            stats.samples,
+            ANSI_RESET
+        );
+        print_allocations(result);
+    }
+}
+
+/// Writes results to stdout in the selected [`Format`].
+///
+/// Text and CSV are printed as results come in; JSON is collected and printed as one array by
+/// [`Reporter::finish`].
+pub struct Reporter {
+    format: Format,
+    bench: bool,
+    records: Vec<Record>,
+}
+
+impl Reporter {
+    /// With `bench` set, results are reported together with their benchmark [`Stats`].
+    pub fn new(format: Format, bench: bool) -> Self {
+        if format == Format::Csv {
+            let bench_header = if bench { CSV_BENCH_HEADER } else { "" };
+            let allocations_header = if memory::ENABLED {
+                CSV_ALLOCATIONS_HEADER
+            } else {
+                ""
+            };
+            println!("{}{}{}", CSV_HEADER, bench_header, allocations_header);
+        }
+
+        Reporter {
+            format,
+            bench,
+            records: vec![],
+        }
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Starts the output for `day`. Only visible in text output.
+    pub fn day(&mut self, day: u8) {
+        if self.format == Format::Text {
+            println!("----------");
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("----------");
+        }
+    }
+
+    /// Reports a part. `stats` are only shown in benchmark mode.
+    pub fn part(&mut self, result: &PartResult, stats: Option<&Stats>) {
+        let mut record = Record::from_result(result, self.bench.then(|| stats.cloned()));
+        if memory::ENABLED {
+            record.allocations = Some(result.allocations);
+        }
+        self.record(record, || match stats {
+            Some(stats) => print_bench_result(result, stats),
+            None => print_result(result),
+        });
+    }
+
+    /// Reports a day that could not be run, because it has no solution or no input.
+    pub fn not_run(&mut self, day: u8) {
+        if self.format == Format::Text {
+            println!("Not solved.");
+            return;
+        }
+
+        for part in 1..=2 {
+            let mut record = Record::not_run(day, part, self.bench);
+            if memory::ENABLED {
+                record.allocations = Some(None);
+            }
+            self.record(record, || {});
+        }
+    }
+
+    fn record(&mut self, record: Record, print_text: impl FnOnce()) {
+        match self.format {
+            Format::Text => print_text(),
+            Format::Csv => println!("{}", record.to_csv()),
+            Format::Json => self.records.push(record),
+        }
+    }
+
+    pub fn finish(self) {
+        if self.format == Format::Json {
+            let lines: Vec<String> = self
+                .records
+                .iter()
+                .map(|r| format!("  {}", r.to_json()))
+                .collect();
+            if lines.is_empty() {
+                println!("[]");
+            } else {
+                println!("[\n{}\n]", lines.join(",\n"));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved(answer: &str) -> PartResult {
+        PartResult {
+            day: 10,
+            part: 2,
+            outcome: Outcome::Solved(answer.to_string()),
+            elapsed: Duration::from_nanos(1500),
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_json_record() {
+        assert_eq!(
+            Record::from_result(&solved("#.\n\"a\""), None).to_json(),
+            r##"{"day": 10, "part": 2, "answer": "#.\n\"a\"", "elapsed_ns": 1500, "status": "solved", "error": null}"##
+        );
+        assert_eq!(
+            Record::not_run(3, 1, false).to_json(),
+            r#"{"day": 3, "part": 1, "answer": null, "elapsed_ns": null, "status": "unsolved", "error": null}"#
+        );
+
+        let failed = PartResult {
+            outcome: Outcome::Failed("bad \"input\"".into()),
+            ..solved("")
+        };
+        assert_eq!(
+            Record::from_result(&failed, None).to_json(),
+            r#"{"day": 10, "part": 2, "answer": null, "elapsed_ns": 1500, "status": "failed", "error": "bad \"input\""}"#
+        );
+    }
+
+    #[test]
+    fn test_csv_record() {
+        assert_eq!(
+            Record::from_result(&solved("24000"), None).to_csv(),
+            "10,2,24000,1500,solved,"
+        );
+        assert_eq!(
+            Record::from_result(&solved("a,\"b\"\nc"), None).to_csv(),
+            "10,2,\"a,\"\"b\"\"\nc\",1500,solved,"
+        );
+        assert_eq!(Record::not_run(3, 1, false).to_csv(), "3,1,,,unsolved,");
+
+        let skipped = PartResult::aborted(10, 1, Outcome::Skipped);
+        assert_eq!(
+            Record::from_result(&skipped, None).to_csv(),
+            "10,1,,,skipped,"
+        );
+        let timed_out = PartResult {
+            outcome: Outcome::TimedOut(Duration::from_secs(5)),
+            ..solved("")
+        };
+        assert_eq!(
+            Record::from_result(&timed_out, None).to_csv(),
+            "10,2,,,timed_out,timed out after 5s"
+        );
+    }
+
+    #[test]
+    fn test_bench_record() {
+        let stats = Stats {
+            samples: 3,
+            min: Duration::from_nanos(1000),
+            median: Duration::from_nanos(1200),
+            mean: Duration::from_nanos(1300),
+            stddev: Duration::from_nanos(50),
+        };
+        let record = Record::from_result(&solved("24000"), Some(Some(stats)));
+
+        assert_eq!(
+            record.to_csv(),
+            "10,2,24000,1200,solved,,3,1000,1200,1300,50"
+        );
+        assert_eq!(
+            record.to_json(),
+            r#"{"day": 10, "part": 2, "answer": "24000", "elapsed_ns": 1200, "status": "solved", "error": null, "samples": 3, "min_ns": 1000, "median_ns": 1200, "mean_ns": 1300, "stddev_ns": 50}"#
+        );
+        assert_eq!(Record::not_run(3, 1, true).to_csv(), "3,1,,,unsolved,,,,,,");
+    }
+
+    #[test]
+    fn test_allocations_record() {
+        let allocations = Allocations {
+            count: 4,
+            bytes: 2048,
+            peak: 1024,
+        };
+        let record = Record {
+            allocations: Some(Some(allocations)),
+            ..Record::from_result(&solved("24000"), None)
+        };
+
+        assert_eq!(record.to_csv(), "10,2,24000,1500,solved,,4,2048,1024");
+        assert_eq!(
+            record.to_json(),
+            r#"{"day": 10, "part": 2, "answer": "24000", "elapsed_ns": 1500, "status": "solved", "error": null, "allocations": 4, "allocated_bytes": 2048, "peak_bytes": 1024}"#
+        );
+
+        let not_run = Record {
+            allocations: Some(None),
+            ..Record::not_run(3, 1, false)
+        };
+        assert_eq!(not_run.to_csv(), "3,1,,,unsolved,,,,");
+    }
+}
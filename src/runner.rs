@@ -0,0 +1,470 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{diff, Answers, Check};
+use crate::config::config;
+use crate::log::{self, Level};
+use crate::{
+    default_year, parse_day, read_input, BenchConfig, Day, Format, InputError, InputSource, Limits,
+    Outcome, PartResult, Registry, Reporter, Stats, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    RUN_PART_ARG,
+};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+use std::{process, thread};
+
+struct Args {
+    /// Year whose days are solved, see `default_year`.
+    year: u16,
+    /// Only solve this day, like a single solution would.
+    day: Option<u8>,
+    format: Format,
+    bench: Option<BenchConfig>,
+    check: bool,
+    /// Only run this part of every day.
+    part: Option<u8>,
+    /// Number of days solved concurrently, `None` to solve them one after another.
+    jobs: Option<usize>,
+    /// Run every part in a child process that is killed once it exceeds these limits.
+    limits: Option<Limits>,
+    /// Solve only this day and part and report it to the parent process, see `run_guarded`.
+    run_part: Option<(u8, u8)>,
+    /// Read the input of `day` from this file or stdin instead of `inputs/<day>.txt`.
+    input: Option<InputSource>,
+    /// Record the solved answers of `day` in `answers/<day>.toml`.
+    save_answers: bool,
+    /// Log level of the solutions, `AOC_LOG` if not set.
+    log: Option<Level>,
+}
+
+fn parse_jobs(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) | Err(_) => Err(format!("invalid number of jobs \"{}\"", s)),
+        Ok(jobs) => Ok(jobs),
+    }
+}
+
+fn parse_day_part(s: &str) -> Result<(u8, u8), String> {
+    let (day, part) = s.split_once(':').ok_or("expected <day>:<part>")?;
+    let day = day
+        .parse()
+        .map_err(|_| format!("invalid day \"{}\"", day))?;
+    Ok((day, crate::parse_part(part)?))
+}
+
+fn parse_args(day: Option<u8>) -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let jobs = args.opt_value_from_fn(["-j", "--jobs"], parse_jobs)?;
+    let serial = args.contains("--serial");
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(default_year),
+        format: args
+            .opt_value_from_str(["-f", "--format"])?
+            .unwrap_or(config().format),
+        bench: BenchConfig::from_args(&mut args)?,
+        check: args.contains("--check"),
+        part: args.opt_value_from_fn(["-p", "--part"], crate::parse_part)?,
+        // `--serial` takes precedence, e.g. to override `--jobs` in an alias.
+        jobs: jobs.filter(|_| !serial),
+        limits: Limits::from_args(&mut args)?,
+        run_part: args.opt_value_from_fn(RUN_PART_ARG, parse_day_part)?,
+        input: args.opt_value_from_str(["-i", "--input"])?,
+        save_answers: args.contains("--save-answers"),
+        log: Level::from_args(&mut args),
+        // free arguments are parsed last, e.g. `cargo solve 08 -- --part 1` passes `08 -- --part 1`.
+        day: match day {
+            Some(day) => Some(day),
+            None => args.opt_free_from_fn(parse_day)?,
+        },
+    })
+}
+
+fn solve_part(args: &Args, solution: &Day, part: u8, input: &str) -> (PartResult, Option<Stats>) {
+    match &args.bench {
+        Some(config) => crate::bench(config, || solution.run_catching(part, input)),
+        None => (solution.run_catching(part, input), None),
+    }
+}
+
+/// Results of both parts of a day, `None` if the day has no solution or no input.
+type DayResults = Option<Vec<(PartResult, Option<Stats>)>>;
+
+fn solve_day(args: &Args, registry: &Registry, day: u8) -> DayResults {
+    let solution = registry.get(args.year, day)?;
+    let input = crate::try_read_file(args.year, "inputs", day).ok()?;
+
+    let results = (1..=2)
+        .map(|part| {
+            if args.part.is_some_and(|p| p != part) {
+                (PartResult::aborted(day, part, Outcome::Skipped), None)
+            } else if let Some(limits) = &args.limits {
+                crate::run_guarded(limits, day, part, None)
+            } else {
+                solve_part(args, solution, part, &input)
+            }
+        })
+        .collect();
+
+    Some(results)
+}
+
+/// Solves a single part in a child process started by `run_guarded` and reports it back.
+fn solve_child(args: &Args, registry: &Registry, day: u8, part: u8) {
+    let solution = registry.get(args.year, day);
+    let input = read_input(args.year, day, args.input.as_ref());
+
+    let (result, stats) = match (solution, input) {
+        (Some(solution), Ok(input)) => solve_part(args, solution, part, &input),
+        _ => (
+            PartResult::aborted(day, part, Outcome::Failed("no solution or input".into())),
+            None,
+        ),
+    };
+
+    crate::write_result(&result, stats.as_ref());
+}
+
+/// Solves days on `jobs` threads, passing their results to `report` in day order.
+fn solve_parallel(
+    args: &Args,
+    registry: &Registry,
+    jobs: usize,
+    mut report: impl FnMut(u8, DayResults),
+) {
+    let next_day = AtomicU8::new(1);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let next_day = &next_day;
+            scope.spawn(move || loop {
+                let day = next_day.fetch_add(1, Ordering::Relaxed);
+                if day > 25 {
+                    break;
+                }
+                sender.send((day, solve_day(args, registry, day))).unwrap();
+            });
+        }
+        drop(sender);
+
+        // days finish out of order, hold back results until all earlier days are reported.
+        let mut pending = BTreeMap::new();
+        let mut next = 1;
+        for (day, results) in receiver {
+            pending.insert(day, results);
+            while let Some(results) = pending.remove(&next) {
+                report(next, results);
+                next += 1;
+            }
+        }
+    });
+}
+
+/// Reports both parts of `day`, returning the time spent on solved parts and whether any part
+/// failed. When benchmarking, the median time of each part is used.
+fn report_day(reporter: &mut Reporter, day: u8, results: DayResults) -> (Duration, bool) {
+    reporter.day(day);
+
+    let results = match results {
+        Some(results) => results,
+        None => {
+            reporter.not_run(day);
+            return (Duration::ZERO, false);
+        }
+    };
+
+    let mut total = Duration::ZERO;
+    let mut failed = false;
+    for (result, stats) in results {
+        reporter.part(&result, stats.as_ref());
+
+        failed |= result.outcome.is_failure();
+        total += match (result.outcome, stats) {
+            (Outcome::Solved(_), Some(stats)) => stats.median,
+            (Outcome::Solved(_), None) => result.elapsed,
+            _ => Duration::ZERO,
+        };
+    }
+
+    (total, failed)
+}
+
+/// Reruns every day of the year and compares its answers against the ones recorded in `answers`.
+/// Returns `false` if any answer changed.
+fn check_all(registry: &Registry, args: &Args) -> bool {
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut unsolved = vec![];
+    let mut missing_input = vec![];
+    let mut locked_input = vec![];
+    let mut not_recorded = vec![];
+
+    for day in 1..=25 {
+        if args.day.is_some_and(|d| d != day) {
+            continue;
+        }
+
+        let solution = match registry.get(args.year, day) {
+            Some(solution) => solution,
+            None => {
+                unsolved.push(format!("{:02}", day));
+                continue;
+            }
+        };
+
+        let input = match crate::try_read_file(args.year, "inputs", day) {
+            Ok(input) => input,
+            Err(InputError::Locked(_)) => {
+                locked_input.push(format!("{:02}", day));
+                continue;
+            }
+            Err(_) => {
+                missing_input.push(format!("{:02}", day));
+                continue;
+            }
+        };
+
+        let answers = match Answers::load(args.year, day) {
+            Ok(answers) => answers.unwrap_or_default(),
+            Err(e) => {
+                println!("Day {:02}: {}FAILED{} ({})", day, ANSI_BOLD, ANSI_RESET, e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        for part in 1..=2 {
+            if args.part.is_some_and(|p| p != part) {
+                continue;
+            }
+
+            let result = match &args.limits {
+                Some(limits) => crate::run_guarded(limits, day, part, None).0,
+                None => solution.run_catching(part, &input),
+            };
+            let label = format!("{:02} (part {})", day, part);
+
+            match Check::new(answers.get(part), &result.outcome) {
+                Check::Passed => {
+                    println!("Day {}: ok", label);
+                    passed += 1;
+                }
+                Check::Failed { expected, actual } => {
+                    println!("Day {}: {}FAILED{}", label, ANSI_BOLD, ANSI_RESET);
+                    println!("{}", diff(&expected, &actual));
+                    failed += 1;
+                }
+                Check::Errored(e) => {
+                    println!("Day {}: {}FAILED{} ({})", label, ANSI_BOLD, ANSI_RESET, e);
+                    failed += 1;
+                }
+                Check::Unsolved => unsolved.push(label),
+                Check::NotRecorded => not_recorded.push(label),
+            }
+        }
+    }
+
+    println!("---");
+    println!("🎄 {} passed, {} failed.", passed, failed);
+    for (title, days) in [
+        ("Unsolved", unsolved),
+        ("Missing input", missing_input),
+        ("Encrypted input without a key", locked_input),
+        ("No recorded answer", not_recorded),
+    ] {
+        if !days.is_empty() {
+            println!("{}: {}", title, days.join(", "));
+        }
+    }
+
+    failed == 0
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// Solves a single day, e.g. `cargo solve 08`. Unlike a day among all others, a missing solution
+/// or input is an error here.
+fn solve_single(args: &Args, registry: &Registry, day: u8) {
+    let solution = match registry.get(args.year, day) {
+        Some(solution) => solution,
+        None => fail(&format!(
+            "Day {:02} of {} has no solution. Run `cargo scaffold {} --year {}` to create one.",
+            day, args.year, day, args.year
+        )),
+    };
+    let input = match read_input(args.year, day, args.input.as_ref()) {
+        Ok(input) => input,
+        Err(e) => fail(&format!("Failed to read input: {}", e)),
+    };
+
+    let mut reporter = Reporter::new(args.format, args.bench.is_some());
+    let mut results = vec![];
+    for part in 1..=2 {
+        let (result, stats) = if args.part.is_some_and(|p| p != part) {
+            (PartResult::aborted(day, part, Outcome::Skipped), None)
+        } else if let Some(limits) = &args.limits {
+            // the child can not read stdin again, it gets the input from the runner instead.
+            let stdin = matches!(args.input, Some(InputSource::Stdin)).then_some(input.as_str());
+            crate::run_guarded(limits, day, part, stdin)
+        } else {
+            solve_part(args, solution, part, &input)
+        };
+        reporter.part(&result, stats.as_ref());
+        results.push(result);
+    }
+    reporter.finish();
+
+    if args.save_answers {
+        save_answers(args, day, &results);
+    }
+
+    if results.iter().any(|r| r.outcome.is_failure()) {
+        process::exit(1);
+    }
+}
+
+fn save_answers(args: &Args, day: u8, results: &[PartResult]) {
+    let saved = Answers::load(args.year, day).and_then(|answers| {
+        let mut answers = answers.unwrap_or_default();
+        for result in results {
+            if let Outcome::Solved(answer) = &result.outcome {
+                answers.set(result.part, answer.clone());
+            }
+        }
+        answers.save(args.year, day)
+    });
+
+    match saved {
+        Ok(path) => {
+            if args.format == Format::Text {
+                println!("---");
+                println!("🎄 Saved answers to \"{}\".", path.display());
+            }
+        }
+        Err(e) => fail(&format!("Failed to save answers: {}", e)),
+    }
+}
+
+/// Entry point of the runner (`cargo solve`, `cargo all`) and of the day binaries, see
+/// [`solve!`](crate::solve). With `day` set, only that day is solved and no day is read from the
+/// arguments.
+pub fn run(day: Option<u8>) {
+    let args = match parse_args(day) {
+        Ok(args) => args,
+        Err(e) => fail(&format!("Failed to process arguments: {}", e)),
+    };
+
+    if let Some(level) = args.log {
+        log::set_level(level);
+    }
+
+    let registry = crate::registry();
+
+    if let Some((day, part)) = args.run_part {
+        solve_child(&args, &registry, day, part);
+        return;
+    }
+
+    if args.check {
+        let ok = check_all(&registry, &args);
+        process::exit(if ok { 0 } else { 1 });
+    }
+
+    match args.day {
+        Some(day) => {
+            solve_single(&args, &registry, day);
+            return;
+        }
+        None if args.input.is_some() || args.save_answers => fail(
+            "`--input` and `--save-answers` only work for a single day, e.g. `cargo solve 08`.",
+        ),
+        None => {}
+    }
+    let mut reporter = Reporter::new(args.format, args.bench.is_some());
+
+    let mut total = Duration::ZERO;
+    let mut failed = false;
+    let mut report = |day, results| {
+        let (elapsed, day_failed) = report_day(&mut reporter, day, results);
+        total += elapsed;
+        failed |= day_failed;
+    };
+
+    match args.jobs {
+        Some(jobs) => solve_parallel(&args, &registry, jobs, report),
+        None => {
+            for day in 1..=25 {
+                report(day, solve_day(&args, &registry, day));
+            }
+        }
+    }
+
+    if reporter.format() == Format::Text {
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+        if let Some(jobs) = args.jobs {
+            println!(
+                "{}Days were solved on {} threads, timings are contended.{}",
+                ANSI_ITALIC, jobs, ANSI_RESET
+            );
+        }
+    }
+
+    reporter.finish();
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks recorded answers against the real inputs. Days whose input is missing, or
+    /// encrypted without a key being available, are skipped.
+    #[test]
+    fn test_recorded_answers() {
+        for solution in crate::registry().iter() {
+            let (year, day) = (solution.year, solution.day);
+            let input = match crate::try_read_file(year, "inputs", day) {
+                Ok(input) => input,
+                Err(e) => {
+                    println!("Skipping {} day {:02}: {}", year, day, e);
+                    continue;
+                }
+            };
+            let answers = match Answers::load(year, day) {
+                Ok(answers) => answers.unwrap_or_default(),
+                Err(e) => panic!("{} day {:02}: {}", year, day, e),
+            };
+
+            for part in (1..=2).filter(|&part| answers.get(part).is_some()) {
+                let result = solution.run_catching(part, &input);
+                match Check::new(answers.get(part), &result.outcome) {
+                    Check::Failed { expected, actual } => panic!(
+                        "{} day {:02} part {}: expected {}, got {}",
+                        year, day, part, expected, actual
+                    ),
+                    Check::Errored(e) => panic!("{} day {:02} part {}: {}", year, day, part, e),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,336 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::memory::{self, Allocations};
+use std::error::Error;
+use std::fmt::Display;
+use std::panic;
+use std::time::{Duration, Instant};
+
+/// A solution for a single day of the advent calendar.
+///
+/// Every `years/<year>/<day>.rs` implements this for a `Day<day>` struct, which is what the
+/// runner calls into, both for a single day (`cargo solve <day>`) and for all days (`cargo all`).
+///
+/// Parts return either `Option<T>` or `Result<T, E>`. `None` marks a part as not solved yet,
+/// while an `Err` is reported as a failure.
+pub trait Solution {
+    /// Day of the advent calendar, `1..=25`.
+    const DAY: u8;
+
+    /// Puzzle input after parsing. Shared by both parts.
+    type Input;
+    type PartOne: Answer;
+    type PartTwo: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError>;
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+}
+
+/// Error returned by a fallible solution.
+///
+/// Any [`std::error::Error`] (e.g. `ParseIntError`) converts into it, so `?` can be used in
+/// [`Solution::parse`]. Other errors can be created with [`SolveError::new`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveError(String);
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError(message.into())
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<E: Error> From<E> for SolveError {
+    fn from(e: E) -> Self {
+        SolveError(e.to_string())
+    }
+}
+
+/// Value returned by a part solver.
+pub trait Answer {
+    fn into_outcome(self) -> Outcome;
+}
+
+impl<T: Display> Answer for Option<T> {
+    fn into_outcome(self) -> Outcome {
+        match self {
+            Some(answer) => Outcome::Solved(answer.to_string()),
+            None => Outcome::Unsolved,
+        }
+    }
+}
+
+impl<T: Display, E: Display> Answer for Result<T, E> {
+    fn into_outcome(self) -> Outcome {
+        match self {
+            Ok(answer) => Outcome::Solved(answer.to_string()),
+            Err(e) => Outcome::Failed(e.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(String),
+    Unsolved,
+    /// Parsing the input or solving the part returned an error.
+    Failed(String),
+    /// The solver panicked, see [`Day::run_catching`].
+    Panicked,
+    /// The part was not run, because only the other part was selected with `--part`.
+    Skipped,
+    /// The part was killed after running into the runner's `--timeout`.
+    TimedOut(Duration),
+    /// The part was killed after exceeding the runner's `--max-memory`, given in bytes.
+    OutOfMemory(u64),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    /// Time spent parsing the input and solving the part.
+    pub elapsed: Duration,
+    /// Heap usage of parsing and solving, only counted with the `count-allocations` feature.
+    pub allocations: Option<Allocations>,
+}
+
+impl PartResult {
+    /// A part that did not run to its end, e.g. one that was skipped, panicked or was killed.
+    /// It has no time or allocations to report.
+    pub fn aborted(day: u8, part: u8, outcome: Outcome) -> Self {
+        PartResult {
+            day,
+            part,
+            outcome,
+            elapsed: Duration::ZERO,
+            allocations: None,
+        }
+    }
+}
+
+/// Parses a day of the advent calendar.
+pub fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day \"{}\", expected 1 to 25", s)),
+    }
+}
+
+/// Parses the value of `--part`.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part \"{}\", expected 1 or 2", s)),
+    }
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        self.error().is_some()
+    }
+
+    /// Describes why the part failed, `None` if it did not.
+    pub fn error(&self) -> Option<String> {
+        match self {
+            Outcome::Failed(e) => Some(e.clone()),
+            Outcome::Panicked => Some("panicked".into()),
+            Outcome::TimedOut(timeout) => Some(format!("timed out after {:?}", timeout)),
+            Outcome::OutOfMemory(limit) => Some(format!(
+                "exceeded the memory limit of {} MB",
+                limit / 1024 / 1024
+            )),
+            Outcome::Solved(_) | Outcome::Unsolved | Outcome::Skipped => None,
+        }
+    }
+}
+
+fn timed<A: Answer>(
+    func: impl FnOnce() -> Result<A, SolveError>,
+) -> (Outcome, Duration, Option<Allocations>) {
+    let ((answer, elapsed), allocations) = memory::measure(|| {
+        let timer = Instant::now();
+        let answer = func();
+        (answer, timer.elapsed())
+    });
+
+    let outcome = match answer {
+        Ok(answer) => answer.into_outcome(),
+        Err(e) => Outcome::Failed(format!("could not parse input: {}", e)),
+    };
+    (outcome, elapsed, allocations)
+}
+
+/// Parses `input` and runs `part` (1 or 2) of solution `S` on it.
+pub fn run_part<S: Solution>(part: u8, input: &str) -> PartResult {
+    let (outcome, elapsed, allocations) = match part {
+        1 => timed(|| S::parse(input).map(|parsed| S::part_one(&parsed))),
+        2 => timed(|| S::parse(input).map(|parsed| S::part_two(&parsed))),
+        _ => panic!("invalid part: {}", part),
+    };
+
+    PartResult {
+        day: S::DAY,
+        part,
+        outcome,
+        elapsed,
+        allocations,
+    }
+}
+
+/// Type-erased handle to a [`Solution`], as stored in a [`Registry`].
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    run: fn(u8, &str) -> PartResult,
+}
+
+impl Day {
+    /// `year` is not part of [`Solution`], it is taken from the directory the solution lives in.
+    pub fn of<S: Solution>(year: u16) -> Self {
+        Day {
+            year,
+            day: S::DAY,
+            run: run_part::<S>,
+        }
+    }
+
+    pub fn run(&self, part: u8, input: &str) -> PartResult {
+        (self.run)(part, input)
+    }
+
+    /// Like [`Day::run`], but reports a panic as [`Outcome::Panicked`], so a panicking solution
+    /// does not take the remaining days down with it.
+    pub fn run_catching(&self, part: u8, input: &str) -> PartResult {
+        panic::catch_unwind(|| self.run(part, input))
+            .unwrap_or_else(|_| PartResult::aborted(self.day, part, Outcome::Panicked))
+    }
+}
+
+/// All solutions known to the runner, ordered by year and day.
+#[derive(Default)]
+pub struct Registry {
+    days: Vec<Day>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution>(&mut self, year: u16) {
+        self.days.retain(|d| (d.year, d.day) != (year, S::DAY));
+        self.days.push(Day::of::<S>(year));
+        self.days.sort_by_key(|d| (d.year, d.day));
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Day> {
+        self.days.iter().find(|d| (d.year, d.day) == (year, day))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Day> {
+        self.days.iter()
+    }
+
+    /// Solutions of `year`, in day order.
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &Day> {
+        self.days.iter().filter(move |d| d.year == year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        const DAY: u8 = 3;
+        type Input = Vec<u32>;
+        type PartOne = Option<u32>;
+        type PartTwo = Result<u32, SolveError>;
+
+        fn parse(input: &str) -> Result<Self::Input, SolveError> {
+            let numbers = input.lines().map(|l| l.parse()).collect::<Result<_, _>>()?;
+            Ok(numbers)
+        }
+
+        fn part_one(input: &Self::Input) -> Option<u32> {
+            input.iter().copied().reduce(|a, b| a + b)
+        }
+
+        fn part_two(input: &Self::Input) -> Result<u32, SolveError> {
+            input
+                .iter()
+                .max()
+                .copied()
+                .ok_or_else(|| SolveError::new("empty input"))
+        }
+    }
+
+    #[test]
+    fn test_run_part() {
+        let result = run_part::<Example>(1, "1\n2\n3");
+        assert_eq!(result.day, 3);
+        assert_eq!(result.outcome, Outcome::Solved("6".into()));
+        assert_eq!(run_part::<Example>(1, "").outcome, Outcome::Unsolved);
+    }
+
+    #[test]
+    fn test_run_part_failed() {
+        assert_eq!(
+            run_part::<Example>(2, "").outcome,
+            Outcome::Failed("empty input".into())
+        );
+        assert_eq!(
+            run_part::<Example>(1, "1\nx").outcome,
+            Outcome::Failed("could not parse input: invalid digit found in string".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("1"), Ok(1));
+        assert_eq!(parse_day("25"), Ok(25));
+        assert!(parse_day("0").is_err());
+        assert!(parse_day("99").is_err());
+        assert!(parse_day("x").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(parse_part("1"), Ok(1));
+        assert_eq!(parse_part("2"), Ok(2));
+        assert!(parse_part("3").is_err());
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = Registry::new();
+        registry.register::<Example>(2022);
+        registry.register::<Example>(2022);
+        registry.register::<Example>(2021);
+
+        assert_eq!(registry.iter().count(), 2);
+        assert_eq!(registry.year(2022).count(), 1);
+        assert!(registry.get(2022, 3).is_some());
+        assert!(registry.get(2022, 4).is_none());
+        assert!(registry.get(2020, 3).is_none());
+        assert_eq!(registry.iter().next().unwrap().year, 2021);
+        assert_eq!(
+            registry.get(2022, 3).unwrap().run(1, "4").outcome,
+            Outcome::Solved("4".into())
+        );
+    }
+}
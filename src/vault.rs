@@ -0,0 +1,139 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{file_path, project_root};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::{env, fs, io};
+
+/// Key of the encrypted inputs as hex, used instead of the key file if set, e.g. in CI.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+const KEY_FILE: &str = ".input-key";
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+pub enum VaultError {
+    InvalidKey,
+    /// The file was encrypted with a different key or is damaged.
+    Undecryptable,
+    IoError(io::Error),
+}
+
+impl Display for VaultError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VaultError::InvalidKey => write!(f, "the key is not 64 hex digits."),
+            VaultError::Undecryptable => {
+                write!(
+                    f,
+                    "could not decrypt, the key does not match or the file is damaged."
+                )
+            }
+            VaultError::IoError(e) => write!(f, "could not read the key: {}", e),
+        }
+    }
+}
+
+/// Encrypted copy of the input of `day`, next to the input itself. Unlike inputs, these can be
+/// committed.
+pub fn encrypted_path(year: u16, day: u8) -> PathBuf {
+    let path = file_path(year, "inputs", day);
+    path.with_file_name(format!("{:02}.txt.enc", day))
+}
+
+/// Key for encrypting inputs with ChaCha20-Poly1305.
+pub struct Key(chacha20poly1305::Key);
+
+impl Key {
+    pub fn generate() -> Self {
+        Key(ChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    /// Local key file, which must not be committed.
+    pub fn path() -> PathBuf {
+        project_root().join(KEY_FILE)
+    }
+
+    /// Reads the key from [`KEY_VAR`] or the key file, `None` if there is neither.
+    pub fn load() -> Result<Option<Self>, VaultError> {
+        if let Ok(hex) = env::var(KEY_VAR) {
+            return Key::from_hex(&hex).map(Some);
+        }
+
+        match fs::read_to_string(Key::path()) {
+            Ok(hex) => Key::from_hex(&hex).map(Some),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(VaultError::IoError(e)),
+        }
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, VaultError> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(VaultError::InvalidKey);
+        }
+
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| VaultError::InvalidKey)?;
+        Ok(Key(*chacha20poly1305::Key::from_slice(&bytes)))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// Encrypts `plaintext` with a random nonce, which is prepended to the result.
+    pub fn encrypt(&self, plaintext: &str) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = ChaCha20Poly1305::new(&self.0)
+            .encrypt(&nonce, plaintext.as_bytes())
+            .expect("encrypting in memory does not fail");
+
+        [nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<String, VaultError> {
+        if data.len() < NONCE_LEN {
+            return Err(VaultError::Undecryptable);
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let plaintext = ChaCha20Poly1305::new(&self.0)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| VaultError::Undecryptable)?;
+        String::from_utf8(plaintext).map_err(|_| VaultError::Undecryptable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let key = Key::generate();
+        let encrypted = key.encrypt("1000\n2000\n");
+
+        assert_eq!(key.decrypt(&encrypted).unwrap(), "1000\n2000\n");
+        assert_ne!(key.encrypt("1000\n2000\n"), encrypted);
+        assert!(Key::generate().decrypt(&encrypted).is_err());
+        assert!(key.decrypt(&encrypted[..8]).is_err());
+    }
+
+    #[test]
+    fn test_hex() {
+        let key = Key::generate();
+        let hex = key.to_hex();
+
+        assert_eq!(Key::from_hex(&format!("{}\n", hex)).unwrap().to_hex(), hex);
+        assert!(Key::from_hex("abc").is_err());
+        assert!(Key::from_hex(&"zz".repeat(32)).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use advent_of_code::helpers::strip_newline;
+use advent_of_code::{Solution, SolveError};
+
+fn elves(in_str: String) -> Vec<u32> {
+    in_str
+        .split("\n\n")
+        .map(|x| x.lines().map(|str| str.parse::<u32>().unwrap()).sum())
+        .collect()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<u32>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(elves(strip_newline(input)))
+    }
+
+    fn part_one(elves: &Self::Input) -> Option<u32> {
+        let highest = elves
+            .iter()
+            .fold(0u32, |acc, x| if *x > acc { *x } else { acc });
+
+        Some(highest)
+    }
+
+    fn part_two(elves: &Self::Input) -> Option<u32> {
+        let mut elves = elves.clone();
+
+        elves.sort();
+        elves.reverse();
+
+        Some(elves.iter().take(3).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day01::parse(&advent_of_code::read_file(2022, "examples", 1)).unwrap();
+        assert_eq!(Day01::part_one(&input), Some(24000));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day01::parse(&advent_of_code::read_file(2022, "examples", 1)).unwrap();
+        assert_eq!(Day01::part_two(&input), Some(45000));
+    }
+}
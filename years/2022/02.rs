@@ -0,0 +1,180 @@
+use advent_of_code::helpers::strip_newline;
+use advent_of_code::{Solution, SolveError};
+use itertools::Itertools;
+
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+enum Play {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Play {
+    pub fn value(self) -> u32 {
+        match self {
+            Play::Rock => 1,
+            Play::Paper => 2,
+            Play::Scissors => 3,
+        }
+    }
+
+    pub fn play_from(self, result: GameResult) -> Self {
+        match result {
+            GameResult::Draw => self,
+            GameResult::Win => match self {
+                Play::Rock => Play::Paper,
+                Play::Paper => Play::Scissors,
+                Play::Scissors => Play::Rock,
+            },
+            GameResult::Loss => match self {
+                Play::Rock => Play::Scissors,
+                Play::Paper => Play::Rock,
+                Play::Scissors => Play::Paper,
+            },
+        }
+    }
+
+    pub fn result_against(self, opponent: Play) -> GameResult {
+        match self {
+            Play::Rock => match opponent {
+                Play::Rock => GameResult::Draw,
+                Play::Paper => GameResult::Loss,
+                Play::Scissors => GameResult::Win,
+            },
+            Play::Paper => match opponent {
+                Play::Rock => GameResult::Win,
+                Play::Paper => GameResult::Draw,
+                Play::Scissors => GameResult::Loss,
+            },
+            Play::Scissors => match opponent {
+                Play::Rock => GameResult::Loss,
+                Play::Paper => GameResult::Win,
+                Play::Scissors => GameResult::Draw,
+            },
+        }
+    }
+
+    pub fn parse_opponent_part1(opponent_play: &str) -> Result<Self, SolveError> {
+        match opponent_play {
+            "A" => Ok(Play::Rock),
+            "B" => Ok(Play::Paper),
+            "C" => Ok(Play::Scissors),
+            _ => Err(SolveError::new(format!(
+                "Invalid opponent play: {opponent_play}"
+            ))),
+        }
+    }
+
+    pub fn parse_my_play_part1(my_play: &str) -> Result<Self, SolveError> {
+        match my_play {
+            "X" => Ok(Play::Rock),
+            "Y" => Ok(Play::Paper),
+            "Z" => Ok(Play::Scissors),
+            _ => Err(SolveError::new(format!("Invalid my play: {my_play}"))),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+enum GameResult {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl GameResult {
+    pub fn get_score(self) -> u32 {
+        match self {
+            Self::Loss => 0,
+            Self::Draw => 3,
+            Self::Win => 6,
+        }
+    }
+
+    pub fn parse_result(result: &str) -> Result<Self, SolveError> {
+        match result {
+            "X" => Ok(Self::Loss),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            _ => Err(SolveError::new(format!("Invalid result: {result}"))),
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<(String, String)>;
+    type PartOne = Result<u32, SolveError>;
+    type PartTwo = Result<u32, SolveError>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let stripped = strip_newline(input);
+
+        stripped
+            .lines()
+            .map(|line| {
+                line.split(' ')
+                    .collect_tuple()
+                    .ok_or_else(|| SolveError::new(format!("Invalid round: {line}")))
+            })
+            .map_ok(|(first, second): (&str, &str)| (first.to_string(), second.to_string()))
+            .collect()
+    }
+
+    fn part_one(rounds: &Self::Input) -> Result<u32, SolveError> {
+        rounds
+            .iter()
+            .map(|(opponent, mine)| {
+                Ok((
+                    Play::parse_opponent_part1(opponent)?,
+                    Play::parse_my_play_part1(mine)?,
+                ))
+            })
+            .map_ok(|(opponent, mine)| mine.value() + mine.result_against(opponent).get_score())
+            .sum()
+    }
+
+    fn part_two(rounds: &Self::Input) -> Result<u32, SolveError> {
+        rounds
+            .iter()
+            .map(|(opponent, result)| {
+                Ok((
+                    Play::parse_opponent_part1(opponent)?,
+                    GameResult::parse_result(result)?,
+                ))
+            })
+            .map_ok(|(opponent, result)| {
+                let my_play = opponent.play_from(result);
+                my_play.value() + result.get_score()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day02::parse(&advent_of_code::read_file(2022, "examples", 2)).unwrap();
+        assert_eq!(Day02::part_one(&input), Ok(15));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day02::parse(&advent_of_code::read_file(2022, "examples", 2)).unwrap();
+        assert_eq!(Day02::part_two(&input), Ok(12));
+    }
+
+    #[test]
+    fn test_invalid_play() {
+        let input = Day02::parse("A Y\nD X").unwrap();
+        assert_eq!(
+            Day02::part_one(&input),
+            Err(SolveError::new("Invalid opponent play: D"))
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use advent_of_code::{Solution, SolveError};
+use itertools::Itertools;
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+pub struct Seating {
+    pub low: u32,
+    pub high: u32,
+}
+
+impl Seating {
+    fn contains(self, second: &Seating) -> bool {
+        second.low >= self.low && second.high <= self.high
+    }
+
+    fn overlaps(self, second: &Seating) -> bool {
+        self.low >= second.low && self.low <= second.high
+            || self.high >= second.low && self.high <= second.high
+    }
+
+    fn parse(input: &str) -> Self {
+        let seating: (&str, &str) = input.split('-').collect_tuple().unwrap();
+
+        Seating {
+            low: seating.0.parse().unwrap(),
+            high: seating.1.parse().unwrap(),
+        }
+    }
+}
+
+pub type SeatingPair = (Seating, Seating);
+
+fn parse_pairs(input: &str) -> Vec<SeatingPair> {
+    input
+        .lines()
+        .map(|line| {
+            let pair: (&str, &str) = line.split(',').collect_tuple().unwrap();
+            (Seating::parse(pair.0), Seating::parse(pair.1))
+        })
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<SeatingPair>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(parse_pairs(input))
+    }
+
+    fn part_one(seating_pairs: &Self::Input) -> Option<u32> {
+        let number = seating_pairs
+            .iter()
+            .filter(|(first, second)| first.contains(second) || second.contains(first))
+            .count() as u32;
+
+        Some(number)
+    }
+
+    fn part_two(seating_pairs: &Self::Input) -> Option<u32> {
+        let number = seating_pairs
+            .iter()
+            .filter(|(first, second)| first.overlaps(second) || second.overlaps(first))
+            .count() as u32;
+
+        Some(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day04::parse(&advent_of_code::read_file(2022, "examples", 4)).unwrap();
+        assert_eq!(Day04::part_one(&input), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day04::parse(&advent_of_code::read_file(2022, "examples", 4)).unwrap();
+        assert_eq!(Day04::part_two(&input), Some(4));
+    }
+}
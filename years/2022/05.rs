@@ -0,0 +1,203 @@
+use advent_of_code::helpers::{numbered_lines, Line, ParseError, Tokens};
+use advent_of_code::{Solution, SolveError};
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Stacks {
+    pub stacks: Vec<Vec<char>>,
+}
+
+impl Stacks {
+    fn parse(input: &str) -> Self {
+        let mut lines: Vec<&str> = input.lines().collect();
+
+        let stack_labels = lines.pop().unwrap();
+        lines.reverse();
+        let mut out = vec![];
+
+        for (x, char) in stack_labels.chars().enumerate() {
+            if char == ' ' {
+                continue;
+            }
+
+            let mut stack = vec![];
+            for line in &lines {
+                let target = line.chars().collect::<Vec<char>>()[x];
+                if target == ' ' {
+                    break;
+                }
+                stack.push(target);
+            }
+            out.push(stack);
+        }
+
+        Stacks { stacks: out }
+    }
+
+    pub fn place_on_stack(&mut self, position: usize, character: char) {
+        self.stacks[position - 1].push(character)
+    }
+
+    pub fn place_multiple_on_stack(&mut self, position: usize, chars: Vec<char>) {
+        self.stacks[position - 1].extend(chars);
+    }
+
+    pub fn pop_from_stack(&mut self, position: usize) -> char {
+        self.stacks[position - 1].pop().unwrap()
+    }
+
+    pub fn execute_instruction(&mut self, instruction: Instruction) {
+        let Instruction { count, from, to } = instruction;
+        for _ in 1..=count {
+            let pulled = self.pop_from_stack(from);
+            self.place_on_stack(to, pulled);
+        }
+    }
+
+    pub fn execute_instruction_9001(&mut self, instruction: Instruction) {
+        let Instruction { count, from, to } = instruction;
+        // silly way of doing this but it made me laugh
+        let mut pulled = vec![];
+        for _ in 1..=count {
+            pulled.push(self.pop_from_stack(from));
+        }
+        pulled.reverse();
+        self.place_multiple_on_stack(to, pulled);
+    }
+
+    pub fn top_of_stacks(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|x| x.iter().last().unwrap())
+            .collect()
+    }
+}
+
+#[derive(Copy, Clone, PartialOrd, PartialEq, Debug)]
+pub struct Instruction {
+    pub count: u32,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Instruction {
+    // e.g. "move 1 from 2 to 1", with stacks numbered from 1 to `stack_count`.
+    pub fn parse(line: Line, stack_count: usize) -> Result<Self, ParseError> {
+        let mut tokens = line.tokens();
+
+        tokens.expect("move")?;
+        let count = tokens.parse("a crate count")?;
+        tokens.expect("from")?;
+        let from = Self::parse_stack(&line, &mut tokens, stack_count)?;
+        tokens.expect("to")?;
+        let to = Self::parse_stack(&line, &mut tokens, stack_count)?;
+        tokens.finish()?;
+
+        Ok(Instruction { count, from, to })
+    }
+
+    fn parse_stack(
+        line: &Line,
+        tokens: &mut Tokens,
+        stack_count: usize,
+    ) -> Result<usize, ParseError> {
+        let token = tokens
+            .next()
+            .ok_or_else(|| line.error_at_end("a stack number"))?;
+        match line.parse_at(token, "a stack number")? {
+            stack if (1..=stack_count).contains(&stack) => Ok(stack),
+            _ => Err(line.error_at(token, format!("a stack number from 1 to {}", stack_count))),
+        }
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = (Stacks, Vec<Instruction>);
+    type PartOne = Option<String>;
+    type PartTwo = Option<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let mut lines = numbered_lines(input);
+
+        let drawing: Vec<&str> = lines
+            .by_ref()
+            .take_while(|line| !line.text.is_empty())
+            .map(|line| line.text)
+            .collect();
+        let stacks = Stacks::parse(&drawing.join("\n"));
+        let instructions = lines
+            .map(|line| Instruction::parse(line, stacks.stacks.len()))
+            .collect::<Result<_, _>>()?;
+
+        Ok((stacks, instructions))
+    }
+
+    fn part_one((stacks, instructions): &Self::Input) -> Option<String> {
+        let mut stacks = stacks.clone();
+
+        for instruction in instructions {
+            stacks.execute_instruction(*instruction);
+        }
+
+        let tops = stacks.top_of_stacks();
+
+        Some(tops)
+    }
+
+    fn part_two((stacks, instructions): &Self::Input) -> Option<String> {
+        let mut stacks = stacks.clone();
+
+        for instruction in instructions {
+            stacks.execute_instruction_9001(*instruction);
+        }
+
+        let tops = stacks.top_of_stacks();
+
+        Some(tops)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day05::parse(&advent_of_code::read_file(2022, "examples", 5)).unwrap();
+        assert_eq!(Day05::part_one(&input), Some("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day05::parse(&advent_of_code::read_file(2022, "examples", 5)).unwrap();
+        assert_eq!(Day05::part_two(&input), Some("MCD".to_string()));
+    }
+
+    #[test]
+    fn test_invalid_instruction() {
+        let input =
+            advent_of_code::read_file(2022, "examples", 5).replace("2 from 2", "2 from two");
+        assert_eq!(
+            Day05::parse(&input).unwrap_err().to_string(),
+            "line 8, column 13: expected a stack number, found \"two\"\n\
+             8 | move 2 from two to 1\n  \
+               |             ^"
+        );
+    }
+
+    #[test]
+    fn test_unknown_stack() {
+        let input = advent_of_code::read_file(2022, "examples", 5).replace("2 from 2", "2 from 4");
+        assert_eq!(
+            Day05::parse(&input).unwrap_err().to_string(),
+            "line 8, column 13: expected a stack number from 1 to 3, found \"4\"\n\
+             8 | move 2 from 4 to 1\n  \
+               |             ^"
+        );
+
+        let input = advent_of_code::read_file(2022, "examples", 5).replace("to 1", "to 0");
+        assert!(Day05::parse(&input).is_err());
+    }
+}
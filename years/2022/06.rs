@@ -0,0 +1,65 @@
+use advent_of_code::{Solution, SolveError};
+use std::collections::HashSet;
+
+fn window_only_unique(window: &[char]) -> bool {
+    let mut found = HashSet::new();
+    for char in window {
+        if found.contains(char) {
+            return false;
+        }
+        found.insert(char);
+    }
+    true
+}
+
+pub fn find_with_window_size(char_vec: &[char], window_size: usize) -> u32 {
+    let windows = char_vec.windows(window_size);
+
+    let mut found = 0;
+    for (pos, window) in windows.enumerate() {
+        if window_only_unique(window) {
+            found = pos as u32;
+            break;
+        }
+    }
+
+    found + window_size as u32
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Vec<char>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.chars().collect())
+    }
+
+    fn part_one(input: &Self::Input) -> Option<u32> {
+        Some(find_with_window_size(input, 4))
+    }
+
+    fn part_two(input: &Self::Input) -> Option<u32> {
+        Some(find_with_window_size(input, 14))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day06::parse(&advent_of_code::read_file(2022, "examples", 6)).unwrap();
+        assert_eq!(Day06::part_one(&input), Some(5));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day06::parse(&advent_of_code::read_file(2022, "examples", 6)).unwrap();
+        assert_eq!(Day06::part_two(&input), Some(23));
+    }
+}
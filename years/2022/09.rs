@@ -0,0 +1,148 @@
+use advent_of_code::helpers::Direction;
+use advent_of_code::{Solution, SolveError};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+struct Rope {
+    pub head: (isize, isize),
+    pub tail: Vec<(isize, isize)>,
+    pub visited: HashSet<(isize, isize)>,
+}
+
+impl Rope {
+    pub fn new(length: usize) -> Self {
+        let mut visited = HashSet::new();
+        visited.insert((0, 0));
+        let tail = vec![(0, 0); length - 1];
+        Self {
+            head: (0, 0),
+            tail,
+            visited,
+        }
+    }
+
+    pub fn update_chain(&mut self) {
+        let mut full_chain = vec![self.head];
+        full_chain.extend(self.tail.clone());
+
+        let mut next = self.head;
+        for i in 0..self.tail.len() {
+            let current = self.tail[i];
+            let result = Rope::move_knot_towards_next(current, next);
+            self.tail[i] = result;
+            next = result;
+        }
+    }
+
+    pub fn move_knot_towards_next(knot: (isize, isize), next: (isize, isize)) -> (isize, isize) {
+        let difference = (next.0 - knot.0, next.1 - knot.1);
+
+        let abs = (difference.0.abs(), difference.1.abs());
+
+        let should_move = abs.0 > 1 || abs.1 > 1;
+
+        if should_move {
+            let move_amount = (difference.0.clamp(-1, 1), difference.1.clamp(-1, 1));
+            (knot.0 + move_amount.0, knot.1 + move_amount.1)
+        } else {
+            knot
+        }
+    }
+
+    pub fn move_in_direction(&mut self, direction: Direction) {
+        self.head = direction.step(self.head);
+        self.update_chain();
+        let new_tail_loc = *self.tail.last().unwrap();
+        self.visited.insert(new_tail_loc);
+    }
+
+    pub fn move_in_direction_multiple(&mut self, direction: Direction, count: usize) {
+        for _ in 0..count {
+            self.move_in_direction(direction);
+        }
+    }
+
+    pub fn parse_command(input: &str) -> (Direction, usize) {
+        let (dir, count): (&str, &str) = input.split(' ').collect_tuple().unwrap();
+
+        let dir = match dir {
+            "U" => Direction::North,
+            "D" => Direction::South,
+            "R" => Direction::East,
+            "L" => Direction::West,
+            _ => panic!("Invalid Dir Parsed"),
+        };
+
+        let count: usize = count.parse().unwrap();
+
+        (dir, count)
+    }
+
+    pub fn num_visited(&self) -> usize {
+        self.visited.len()
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<(Direction, usize)>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.lines().map(Rope::parse_command).collect())
+    }
+
+    fn part_one(commands: &Self::Input) -> Option<u32> {
+        let mut rope = Rope::new(2);
+
+        for (direction, count) in commands {
+            rope.move_in_direction_multiple(*direction, *count);
+        }
+
+        Some(rope.num_visited() as u32)
+    }
+
+    fn part_two(commands: &Self::Input) -> Option<u32> {
+        let mut rope = Rope::new(10);
+
+        for (direction, count) in commands {
+            rope.move_in_direction_multiple(*direction, *count);
+        }
+
+        Some(rope.num_visited() as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // expected answers per example: "" is `examples/09.txt`, "2" is `examples/09-2.txt`.
+    fn part_one_examples() -> Vec<(&'static str, Option<u32>)> {
+        vec![("", Some(13))]
+    }
+
+    fn part_two_examples() -> Vec<(&'static str, Option<u32>)> {
+        vec![("", Some(1)), ("2", Some(36))]
+    }
+
+    #[test]
+    fn test_part_one() {
+        for (name, expected) in part_one_examples() {
+            let input = Day09::parse(&advent_of_code::read_example(2022, 9, name)).unwrap();
+            assert_eq!(Day09::part_one(&input), expected, "example \"{}\"", name);
+        }
+    }
+
+    #[test]
+    fn test_part_two() {
+        for (name, expected) in part_two_examples() {
+            let input = Day09::parse(&advent_of_code::read_example(2022, 9, name)).unwrap();
+            assert_eq!(Day09::part_two(&input), expected, "example \"{}\"", name);
+        }
+    }
+}
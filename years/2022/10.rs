@@ -0,0 +1,146 @@
+use advent_of_code::helpers::Vec2d;
+use advent_of_code::{Solution, SolveError};
+use std::fmt::{Debug, Display, Formatter};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SimpleCPU {
+    register_x: isize,
+    history: Vec<isize>,
+}
+
+impl SimpleCPU {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn noop(&mut self) {
+        self.history.push(self.register_x);
+    }
+
+    pub fn addx(&mut self, amount: isize) {
+        self.history.push(self.register_x);
+        self.register_x += amount;
+        self.history.push(self.register_x);
+    }
+
+    pub fn run_instruction(&mut self, input: &str) {
+        let split: Vec<&str> = input.split(' ').collect();
+
+        match split[0] {
+            "noop" => self.noop(),
+            "addx" => self.addx(split[1].parse().unwrap()),
+            _ => panic!("Invalid Instruction"),
+        }
+    }
+
+    pub fn get_strength_at_cycle(&self, cycle_num: usize) -> isize {
+        self.history[cycle_num - 1] * (cycle_num as isize)
+    }
+}
+
+impl Default for SimpleCPU {
+    fn default() -> Self {
+        Self {
+            register_x: 1,
+            history: vec![1],
+        }
+    }
+}
+
+struct CRTGenerator {
+    pub cycle_history: Vec<isize>,
+}
+
+impl CRTGenerator {
+    fn new(cycle_history: Vec<isize>) -> Self {
+        Self { cycle_history }
+    }
+
+    fn check_cycle(&self, cycle_num: usize) -> bool {
+        let pixel = (cycle_num as isize - 1) % 40;
+        let sprite_pos = self.cycle_history[cycle_num - 1];
+
+        pixel >= sprite_pos - 1 && pixel <= sprite_pos + 1
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+pub struct ScreenPixel(bool);
+
+impl Display for ScreenPixel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", if self.0 { "#" } else { "." })
+    }
+}
+
+impl Display for CRTGenerator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut screen = Vec2d::new_filled(40, 6, ScreenPixel(false));
+        for cycle in 1..=240 {
+            let lit = self.check_cycle(cycle);
+            screen.vec[cycle - 1] = ScreenPixel(lit);
+        }
+        write!(f, "{}", screen)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = SimpleCPU;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let mut cpu = SimpleCPU::new();
+
+        for line in input.lines() {
+            cpu.run_instruction(line);
+        }
+
+        Ok(cpu)
+    }
+
+    fn part_one(cpu: &Self::Input) -> Option<u32> {
+        let wanted_cycles: Vec<usize> = vec![20, 60, 100, 140, 180, 220];
+
+        let sum: isize = wanted_cycles
+            .iter()
+            .map(|x| cpu.get_strength_at_cycle(*x))
+            .sum();
+
+        Some(sum as u32)
+    }
+
+    fn part_two(cpu: &Self::Input) -> Option<String> {
+        let screen = CRTGenerator::new(cpu.history.clone());
+
+        let out = format!("{}", screen);
+
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day10::parse(&advent_of_code::read_file(2022, "examples", 10)).unwrap();
+        assert_eq!(Day10::part_one(&input), Some(13140));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day10::parse(&advent_of_code::read_file(2022, "examples", 10)).unwrap();
+        let expected = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        assert_eq!(Day10::part_two(&input), Some(expected.to_string()));
+    }
+}
@@ -0,0 +1,254 @@
+use advent_of_code::helpers::{numbered_lines, Line, ParseError};
+use advent_of_code::{debug, Solution, SolveError};
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    // e.g. "  Operation: new = old * 19"
+    fn parse(line: Line) -> Result<Self, ParseError> {
+        let mut tokens = line.tokens();
+        tokens.expect("Operation:")?;
+        tokens.expect("new")?;
+        tokens.expect("=")?;
+        tokens.expect("old")?;
+
+        let operation = match tokens.next() {
+            Some("+") => Self::Add(tokens.parse("a number")?),
+            Some("*") => match tokens.clone().next() {
+                Some("old") => {
+                    tokens.next();
+                    Self::Square
+                }
+                _ => Self::Multiply(tokens.parse("a number or \"old\"")?),
+            },
+            Some(operand) => return Err(line.error_at(operand, "\"+\" or \"*\"")),
+            None => return Err(line.error_at_end("\"+\" or \"*\"")),
+        };
+        tokens.finish()?;
+
+        Ok(operation)
+    }
+
+    fn do_operation(self, x: u64) -> u64 {
+        match self {
+            Self::Add(y) => x + y,
+            Self::Multiply(y) => x * y,
+            Self::Square => x * x,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test_divisor: u64,
+    target_true: usize,
+    target_false: usize,
+    pub inspection_count: u128,
+}
+
+impl Monkey {
+    // `input` are the five lines following "Monkey <n>:", of `monkey_count` monkeys in total.
+    fn parse(input: &[Line], monkey_count: usize) -> Result<Self, ParseError> {
+        let items = input[0].strip_prefix("  Starting items: ")?;
+        let starting_items = items
+            .split(", ")
+            .map(|x| input[0].parse_at(x, "a worry level"))
+            .collect::<Result<_, _>>()?;
+
+        let operation = Operation::parse(input[1])?;
+
+        let divisor = input[2].strip_prefix("  Test: divisible by ")?;
+        let divisor = input[2].parse_at(divisor, "a divisor")?;
+
+        let target_true = Self::parse_target(input[3], "    If true: ", monkey_count)?;
+        let target_false = Self::parse_target(input[4], "    If false: ", monkey_count)?;
+
+        Ok(Self {
+            items: starting_items,
+            operation,
+            test_divisor: divisor,
+            target_true,
+            target_false,
+            inspection_count: 0,
+        })
+    }
+
+    // e.g. "    If true: throw to monkey 2"
+    fn parse_target(line: Line, prefix: &str, monkey_count: usize) -> Result<usize, ParseError> {
+        let target = line.strip_prefix(&format!("{}throw to monkey ", prefix))?;
+        match line.parse_at(target, "a monkey number")? {
+            target if target < monkey_count => Ok(target),
+            _ => Err(line.error_at(
+                target,
+                format!("a monkey number from 0 to {}", monkey_count - 1),
+            )),
+        }
+    }
+
+    fn inspect_and_throw(&mut self, item: u64, lcm: Option<u64>) -> (usize, u64) {
+        let inspection = self.operation.clone().do_operation(item);
+        self.inspection_count += 1;
+
+        let result = if let Some(lcm) = lcm {
+            inspection % lcm
+        } else {
+            inspection / 3
+        };
+        let target = if result % self.test_divisor == 0 {
+            self.target_true
+        } else {
+            self.target_false
+        };
+
+        (target, result)
+    }
+
+    // result is (target, item)
+    fn take_turn(&mut self, lcm: Option<u64>) -> Vec<(usize, u64)> {
+        let mut out = vec![];
+        for i in 0..self.items.len() {
+            let item = self.items[i];
+            out.push(self.inspect_and_throw(item, lcm))
+        }
+        self.items.clear();
+        out
+    }
+
+    fn catch_item(&mut self, item: u64) {
+        self.items.push(item)
+    }
+}
+
+fn play_round(monkeys: &mut [Monkey], lcm: Option<u64>) {
+    for i in 0..monkeys.len() {
+        let results = monkeys[i].take_turn(lcm);
+        for (target, item) in results {
+            monkeys[target].catch_item(item);
+        }
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut out = vec![];
+
+    let lines: Vec<Line> = numbered_lines(input).collect();
+    let monkey_prefix = "Monkey ";
+    // monkeys may throw to the ones after them, so all of them are counted first.
+    let monkey_count = lines
+        .iter()
+        .filter(|line| line.text.starts_with(monkey_prefix))
+        .count();
+
+    // there's probably a funky way of doing this with iterators but aoc is already turning
+    // my brain in to mush
+    let mut i = 0;
+    while i < lines.len() {
+        let current_line = lines[i];
+        if current_line.text.starts_with(monkey_prefix) {
+            let args = lines.get(i + 1..=i + 5).ok_or_else(|| {
+                let last = lines[lines.len() - 1];
+                ParseError::new(last.number + 1, 1, "", "the rest of the monkey")
+            })?;
+            let parsed = Monkey::parse(args, monkey_count)?;
+            out.push(parsed);
+            i += 5;
+        } else {
+            //infinite loop escape plan
+            i += 1;
+        }
+    }
+
+    Ok(out)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+    type PartOne = Option<u128>;
+    type PartTwo = Option<u128>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(parse_monkeys(input)?)
+    }
+
+    fn part_one(monkeys: &Self::Input) -> Option<u128> {
+        let mut monkeys = monkeys.clone();
+
+        for _ in 1..=20 {
+            play_round(&mut monkeys, None);
+        }
+
+        let mut monkey_business: Vec<u128> = monkeys.iter().map(|m| m.inspection_count).collect();
+
+        debug!("Inspection counts: {:?}", monkey_business);
+
+        monkey_business.sort();
+
+        let highest = monkey_business.pop().unwrap();
+        let second_highest = monkey_business.pop().unwrap();
+
+        Some(highest * second_highest)
+    }
+
+    fn part_two(monkeys: &Self::Input) -> Option<u128> {
+        let mut monkeys = monkeys.clone();
+
+        let lcm = monkeys.iter().map(|x| x.test_divisor).product();
+
+        for _ in 1..=10000 {
+            play_round(&mut monkeys, Some(lcm));
+        }
+
+        let mut monkey_business: Vec<u128> = monkeys.iter().map(|m| m.inspection_count).collect();
+
+        debug!("Inspection counts: {:?}", monkey_business);
+
+        monkey_business.sort();
+
+        let highest = monkey_business.pop().unwrap();
+        let second_highest = monkey_business.pop().unwrap();
+
+        Some(highest * second_highest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = Day11::parse(&advent_of_code::read_file(2022, "examples", 11)).unwrap();
+        assert_eq!(Day11::part_one(&input), Some(10605));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day11::parse(&advent_of_code::read_file(2022, "examples", 11)).unwrap();
+        assert_eq!(Day11::part_two(&input), Some(2713310158));
+    }
+
+    #[test]
+    fn test_unknown_target() {
+        let input = advent_of_code::read_file(2022, "examples", 11).replacen(
+            "throw to monkey 3",
+            "throw to monkey 4",
+            1,
+        );
+        assert_eq!(
+            Day11::parse(&input).unwrap_err().to_string(),
+            "line 6, column 31: expected a monkey number from 0 to 3, found \"4\"\n\
+             6 |     If false: throw to monkey 4\n  \
+               |                               ^"
+        );
+    }
+}